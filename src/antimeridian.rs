@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+use geo_types::{Coord, CoordNum, LineString, MultiLineString, Polygon};
+use num_traits::NumCast;
+
+fn to_f64<T: CoordNum>(coord: Coord<T>) -> Coord<f64> {
+    Coord {
+        x: NumCast::from(coord.x).unwrap_or(0.0),
+        y: NumCast::from(coord.y).unwrap_or(0.0),
+    }
+}
+
+/// Splits the line string, in longitude/latitude degrees, where it crosses the antimeridian,
+/// i.e. where two successive longitudes are more than 180° apart.
+pub(crate) fn split_line_string<T: CoordNum>(line_string: &LineString<T>) -> MultiLineString<f64> {
+    let mut parts = Vec::new();
+    let mut part: Vec<Coord<f64>> = Vec::new();
+    for coord in line_string.coords().map(|coord| to_f64(*coord)) {
+        if let Some(previous) = part.last().copied() {
+            if (coord.x - previous.x).abs() > 180.0 {
+                let edge = 180f64.copysign(previous.x);
+                let unwrapped = coord.x + 360f64.copysign(previous.x);
+                let t = (edge - previous.x) / (unwrapped - previous.x);
+                let y = previous.y + t * (coord.y - previous.y);
+                part.push(Coord { x: edge, y });
+                parts.push(LineString(core::mem::take(&mut part)));
+                part.push(Coord { x: -edge, y });
+            }
+        }
+        part.push(coord);
+    }
+    if !part.is_empty() {
+        parts.push(LineString(part));
+    }
+    MultiLineString(parts)
+}
+
+/// Shifts longitudes by multiples of 360° so that no ring jumps across the antimeridian, the
+/// polygon then extends past ±180°.
+pub(crate) fn unwrap_polygon<T: CoordNum>(polygon: &Polygon<T>) -> Polygon<f64> {
+    let unwrap = |ring: &LineString<T>| {
+        let mut offset = 0.0;
+        let mut previous: Option<f64> = None;
+        ring.coords()
+            .map(|coord| {
+                let coord = to_f64(*coord);
+                if let Some(previous) = previous {
+                    if coord.x - previous > 180.0 {
+                        offset -= 360.0;
+                    } else if coord.x - previous < -180.0 {
+                        offset += 360.0;
+                    }
+                }
+                previous = Some(coord.x);
+                Coord {
+                    x: coord.x + offset,
+                    y: coord.y,
+                }
+            })
+            .collect::<LineString<f64>>()
+    };
+    Polygon::new(
+        unwrap(polygon.exterior()),
+        polygon.interiors().iter().map(unwrap).collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_line_string, unwrap_polygon};
+    use geo_types::{LineString, MultiLineString, Polygon};
+
+    #[test]
+    fn test_split_line_string() {
+        let line_string = LineString::from(vec![(170.0, 0.0), (-170.0, 10.0), (-160.0, 10.0)]);
+        assert_eq!(
+            split_line_string(&line_string),
+            MultiLineString(vec![
+                LineString::from(vec![(170.0, 0.0), (180.0, 5.0)]),
+                LineString::from(vec![(-180.0, 5.0), (-170.0, 10.0), (-160.0, 10.0)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_unwrap_polygon() {
+        let ring = LineString::from(vec![
+            (170.0, 0.0),
+            (-170.0, 0.0),
+            (-170.0, 10.0),
+            (170.0, 0.0),
+        ]);
+        let unwrapped = unwrap_polygon(&Polygon::new(ring, vec![]));
+        let longitudes: Vec<_> = unwrapped.exterior().coords().map(|c| c.x).collect();
+        assert_eq!(longitudes, vec![170.0, 190.0, 190.0, 170.0]);
+    }
+}
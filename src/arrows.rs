@@ -0,0 +1,83 @@
+use crate::marker::round;
+use crate::{Color, Style};
+use alloc::{format, string::String};
+use core::fmt::Write;
+use geo_types::{Coord, CoordNum, LineString};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+fn to_f64<T: CoordNum>(coord: Coord<T>) -> Option<(f64, f64)> {
+    Some((NumCast::from(coord.x)?, NumCast::from(coord.y)?))
+}
+
+/// Arrow heads every `spacing` along the line, the first at half the spacing from its start,
+/// pointing in the direction of the segment they're on. They are `2 * radius` long and
+/// painted with the stroke color of the line.
+pub(crate) fn direction_arrows<T: CoordNum>(line_string: &LineString<T>, style: &Style) -> String {
+    let spacing = match style.direction_arrows {
+        Some(spacing) if spacing > 0.0 => spacing,
+        _ => return String::new(),
+    };
+    let radius = style.radius as f64;
+    let mut path = String::new();
+    // distance along the line of the next arrow, from the start of the current segment
+    let mut next = spacing / 2.0;
+    for line in line_string.lines() {
+        let ((x0, y0), (x1, y1)) = match (to_f64(line.start), to_f64(line.end)) {
+            (Some(start), Some(end)) => (start, end),
+            _ => continue,
+        };
+        let length = (x1 - x0).hypot(y1 - y0);
+        if length == 0.0 {
+            continue;
+        }
+        let (cos, sin) = ((x1 - x0) / length, (y1 - y0) / length);
+        while next <= length {
+            let (x, y) = (x0 + cos * next, y0 + sin * next);
+            // tip ahead of the point, base corners behind it on both sides
+            write!(
+                path,
+                "M{} {}L{} {}L{} {}z",
+                round(x + cos * radius),
+                round(y + sin * radius),
+                round(x - cos * radius - sin * radius * 0.75),
+                round(y - sin * radius + cos * radius * 0.75),
+                round(x - cos * radius + sin * radius * 0.75),
+                round(y - sin * radius - cos * radius * 0.75),
+            )
+            .unwrap();
+            next += spacing;
+        }
+        next -= length;
+    }
+    if path.is_empty() {
+        return path;
+    }
+    format!(
+        r#"<path class="direction-arrows" d="{}" fill="{}" stroke="none"/>"#,
+        path,
+        style.stroke_color.unwrap_or(Color::Named("black"))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::direction_arrows;
+    use crate::Style;
+    use geo_types::LineString;
+
+    #[test]
+    fn test_direction_arrows() {
+        let style = Style {
+            direction_arrows: Some(4.0),
+            ..Style::default()
+        };
+        let line_string = LineString::from(vec![(0.0, 0.0), (5.0, 0.0), (5.0, 4.0)]);
+        assert_eq!(
+            direction_arrows(&line_string, &style),
+            r#"<path class="direction-arrows" d="M3 0L1 0.75L1 -0.75zM5 2L4.25 0L5.75 0z" fill="black" stroke="none"/>"#
+        );
+        assert_eq!(direction_arrows(&line_string, &Style::default()), "");
+    }
+}
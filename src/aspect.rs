@@ -0,0 +1,60 @@
+use core::fmt::{Display, Formatter, Result};
+
+/// Point of the viewbox aligned with the same point of the viewport when their aspect ratios
+/// differ, see [`Svg::with_preserve_aspect_ratio`](crate::Svg::with_preserve_aspect_ratio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AspectAlign {
+    /// Stretches the viewbox to the viewport, distorting the geometries.
+    None,
+    XMinYMin,
+    XMidYMin,
+    XMaxYMin,
+    XMinYMid,
+    XMidYMid,
+    XMaxYMid,
+    XMinYMax,
+    XMidYMax,
+    XMaxYMax,
+}
+
+/// Whether the viewbox is scaled to fit inside the viewport or to cover it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetOrSlice {
+    /// The whole viewbox is visible, leaving space along one side of the viewport.
+    Meet,
+    /// The viewport is filled, cutting off part of the viewbox.
+    Slice,
+}
+
+/// Value of the `preserveAspectRatio` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PreserveAspectRatio(pub AspectAlign, pub MeetOrSlice);
+
+impl Default for PreserveAspectRatio {
+    fn default() -> Self {
+        PreserveAspectRatio(AspectAlign::XMidYMid, MeetOrSlice::Meet)
+    }
+}
+
+impl Display for PreserveAspectRatio {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        let align = match self.0 {
+            // meet or slice has no effect without alignment
+            AspectAlign::None => return write!(fmt, "none"),
+            AspectAlign::XMinYMin => "xMinYMin",
+            AspectAlign::XMidYMin => "xMidYMin",
+            AspectAlign::XMaxYMin => "xMaxYMin",
+            AspectAlign::XMinYMid => "xMinYMid",
+            AspectAlign::XMidYMid => "xMidYMid",
+            AspectAlign::XMaxYMid => "xMaxYMid",
+            AspectAlign::XMinYMax => "xMinYMax",
+            AspectAlign::XMidYMax => "xMidYMax",
+            AspectAlign::XMaxYMax => "xMaxYMax",
+        };
+        let meet_or_slice = match self.1 {
+            MeetOrSlice::Meet => "meet",
+            MeetOrSlice::Slice => "slice",
+        };
+        write!(fmt, "{} {}", align, meet_or_slice)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::decoration::nice_distance;
+use crate::graticule::multiples;
+use crate::marker::round;
+use crate::{Color, DominantBaseline, Label, Style, TextAnchor, ViewBox};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+/// X and y axes drawn along the bottom and left edges of the final viewbox, with inward
+/// ticks labeled with their coordinate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axes {
+    /// Distance between ticks, a round fifth of the viewbox extent on each axis if unset.
+    pub interval: Option<f64>,
+    pub color: Color,
+}
+
+impl Axes {
+    pub fn new() -> Self {
+        Self {
+            interval: None,
+            color: Color::Named("black"),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: f64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn ticks(&self, min: f64, max: f64) -> Vec<f64> {
+        self.interval
+            .or_else(|| nice_distance((max - min) / 5.0))
+            .map(|interval| multiples(interval, min, max))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn to_svg_str(&self, viewbox: &ViewBox) -> String {
+        let (min_x, min_y) = (viewbox.min_x(), viewbox.min_y());
+        let (max_x, max_y) = (viewbox.max_x(), viewbox.max_y());
+        let size = (max_x - min_x).min(max_y - min_y);
+        let tick = size * 0.02;
+        let text_style = Style {
+            font_size: Some((size * 0.03) as f32),
+            ..Style::default()
+        };
+        let x_style = Style {
+            dominant_baseline: Some(DominantBaseline::Auto),
+            ..text_style.clone()
+        };
+        let y_style = Style {
+            text_anchor: Some(TextAnchor::Start),
+            ..text_style
+        };
+
+        let mut path = format!(
+            "M{} {}H{}M{} {}V{}",
+            min_x, max_y, max_x, min_x, max_y, min_y
+        );
+        let mut labels = String::new();
+        for x in self.ticks(min_x, max_x) {
+            write!(path, "M{} {}v{}", round(x), max_y, round(-tick)).unwrap();
+            let label = Label::new(
+                round(x),
+                round(max_y - tick * 1.5),
+                &round(x).to_string(),
+                &x_style,
+            );
+            labels.push_str(&label.to_string());
+        }
+        for y in self.ticks(min_y, max_y) {
+            write!(path, "M{} {}h{}", min_x, round(y), round(tick)).unwrap();
+            let label = Label::new(
+                round(min_x + tick * 1.5),
+                round(y),
+                &round(y).to_string(),
+                &y_style,
+            );
+            labels.push_str(&label.to_string());
+        }
+        format!(
+            r#"<g class="axes" fill="{color}"><path d="{path}" fill="none" stroke="{color}" stroke-width="{width}"/>{labels}</g>"#,
+            color = self.color,
+            path = path,
+            width = round(size * 0.002),
+            labels = labels,
+        )
+    }
+}
+
+impl Default for Axes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Axes;
+    use crate::ToSvg;
+    use geo_types::Rect;
+
+    #[test]
+    fn test_axes() {
+        let rect = Rect::new((1.0, 1.0), (99.0, 49.0));
+        let svg = rect.to_svg().with_axes(Axes::new()).to_string();
+        assert!(svg.contains(r#"<path d="M0 50H100M0 50V0M0 50v-1M20 50v-1"#));
+        assert!(svg.contains(r#"M0 10h1M0 20h1"#));
+        assert!(svg.contains(r#"<text x="20.0" y="48.5" text-anchor="middle" dominant-baseline="auto" font-size="1.5">20</text>"#));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::defs::is_generated_id;
+use crate::{InvalidCoordinates, InvalidGeometryError, Style, StyleError, Svg, ToSvg, ToSvgStr};
+use alloc::collections::BTreeSet;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Result};
+
+/// Reason why [`TryToSvg::to_svg_checked`] refused to render a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgError {
+    /// A style would render broken or invisible svg, see [`Style::validate`].
+    ///
+    /// [`Style::validate`]: crate::Style::validate
+    Style(StyleError),
+    /// Coordinates are NaN, infinite or can't be converted to a `f64`.
+    Coordinates(InvalidGeometryError),
+    /// Several elements have this id, e.g. because [`Svg::with_id`] also sets it on siblings.
+    DuplicateId(String),
+    /// An element has an id of the form generated for definitions, e.g. `clip-0` or
+    /// `symbol-pin`, which it could take from them.
+    ReservedId(String),
+}
+
+impl Display for SvgError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            SvgError::Style(error) => write!(fmt, "invalid style: {}", error),
+            SvgError::Coordinates(error) => write!(fmt, "{}", error),
+            SvgError::DuplicateId(id) => write!(fmt, "duplicate id: {}", id),
+            SvgError::ReservedId(id) => write!(fmt, "id reserved for definitions: {}", id),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SvgError {}
+
+impl From<StyleError> for SvgError {
+    fn from(error: StyleError) -> Self {
+        SvgError::Style(error)
+    }
+}
+
+impl From<InvalidGeometryError> for SvgError {
+    fn from(error: InvalidGeometryError) -> Self {
+        SvgError::Coordinates(error)
+    }
+}
+
+/// Rendering which fails on the mistakes that [`ToSvg`] renders anyway, into svg that is
+/// invalid or doesn't show what was meant.
+pub trait TryToSvg {
+    fn to_svg_checked(&self) -> core::result::Result<String, SvgError>;
+}
+
+impl<T: ToSvgStr> TryToSvg for T {
+    fn to_svg_checked(&self) -> core::result::Result<String, SvgError> {
+        self.to_svg().to_svg_checked()
+    }
+}
+
+/// Ids given to the elements of the svg, its siblings, clip, mask and insets, once per
+/// element.
+pub(crate) fn element_ids(svg: &Svg) -> Vec<String> {
+    let mut ids: Vec<String> = svg
+        .items
+        .iter()
+        .flat_map(|item| item.ids(&svg.style))
+        .collect();
+    if let Some(clip) = svg.clip {
+        ids.extend(clip.ids(&Style::default()));
+    }
+    for nested in nested(svg) {
+        ids.extend(element_ids(nested));
+    }
+    ids
+}
+
+/// The documents drawn as part of the svg: its siblings, mask and insets.
+fn nested<'a, 'b>(svg: &'b Svg<'a>) -> impl Iterator<Item = &'b Svg<'a>> {
+    svg.siblings
+        .iter()
+        .chain(svg.mask.iter().filter_map(|mask| mask.svg()))
+        .chain(svg.insets.iter().map(|inset| &inset.svg))
+}
+
+/// Checks the styles of the svg and the documents drawn in it.
+fn check_styles(svg: &Svg) -> core::result::Result<(), SvgError> {
+    svg.style.validate()?;
+    nested(svg).try_for_each(check_styles)
+}
+
+/// Checks that the ids of the elements are unique and not reserved for definitions.
+fn check_ids(svg: &Svg) -> core::result::Result<(), SvgError> {
+    let mut ids = BTreeSet::new();
+    for id in element_ids(svg) {
+        if is_generated_id(&id) {
+            return Err(SvgError::ReservedId(id));
+        }
+        if !ids.insert(id.clone()) {
+            return Err(SvgError::DuplicateId(id));
+        }
+    }
+    Ok(())
+}
+
+impl Svg<'_> {
+    /// Renders the document after checking its styles, coordinates and ids, see
+    /// [`TryToSvg`].
+    pub fn to_svg_checked(&self) -> core::result::Result<String, SvgError> {
+        check_styles(self)?;
+        check_ids(self)?;
+        Ok(self.try_to_string(InvalidCoordinates::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SvgError, TryToSvg};
+    use crate::{InvalidGeometryError, Style, StyleError, Styled, ToSvg};
+    use geo_types::{LineString, Point, Rect};
+
+    #[test]
+    fn test_to_svg_checked() {
+        let point = Point::new(1.0, 2.0);
+        assert_eq!(point.to_svg_checked(), Ok(point.to_svg().to_string()));
+
+        let nan = Point::new(f64::NAN, 2.0);
+        assert_eq!(
+            nan.to_svg_checked(),
+            Err(SvgError::Coordinates(InvalidGeometryError { count: 1 }))
+        );
+
+        let svg = point.to_svg().with_stroke_width(-1.0);
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::Style(StyleError::NegativeStrokeWidth(-1.0)))
+        );
+
+        let line_string = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let svg = point
+            .to_svg()
+            .and(line_string.to_svg())
+            .with_id("road".into());
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::DuplicateId("road".into()))
+        );
+        let svg = point
+            .to_svg()
+            .with_id("stop".into())
+            .and(line_string.to_svg().with_id("road".into()));
+        assert!(svg.to_svg_checked().is_ok());
+    }
+
+    #[test]
+    fn test_nested_ids() {
+        let point = Point::new(1.0, 2.0);
+        let focus = Rect::new((0.0, 0.0), (5.0, 5.0));
+        let svg = point
+            .to_svg()
+            .with_id("focus".into())
+            .with_mask(focus.to_svg().with_id("focus".into()));
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::DuplicateId("focus".into()))
+        );
+
+        let svg = point.to_svg().with_id("stop".into()).inset(
+            point.to_svg().with_id("stop".into()),
+            0.0,
+            0.0,
+            1.0,
+            1.0,
+        );
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::DuplicateId("stop".into()))
+        );
+
+        let clip = Styled::new(
+            focus,
+            Style {
+                id: Some("stop".into()),
+                ..Style::default()
+            },
+        );
+        let svg = point.to_svg().with_id("stop".into()).with_clip(&clip);
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::DuplicateId("stop".into()))
+        );
+
+        let svg = point
+            .to_svg()
+            .with_mask(focus.to_svg().with_stroke_width(-1.0));
+        assert_eq!(
+            svg.to_svg_checked(),
+            Err(SvgError::Style(StyleError::NegativeStrokeWidth(-1.0)))
+        );
+    }
+
+    #[test]
+    fn test_reserved_ids() {
+        let point = Point::new(1.0, 2.0);
+        for id in ["clip-0", "mask-12", "gradient-1", "filter-3", "symbol-pin"] {
+            assert_eq!(
+                point.to_svg().with_id(id.into()).to_svg_checked(),
+                Err(SvgError::ReservedId(id.into()))
+            );
+        }
+        for id in ["clip", "clip-a", "clipper-0", "masks-1"] {
+            assert!(point.to_svg().with_id(id.into()).to_svg_checked().is_ok());
+        }
+    }
+}
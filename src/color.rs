@@ -1,9 +1,19 @@
-use std::fmt::{Display, Formatter, Result};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result};
+use core::str::FromStr;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Color {
     Named(&'static str),
     Rgb(u8, u8, u8),
+    /// Color with an alpha between 0 (transparent) and 1 (opaque).
+    Rgba(u8, u8, u8, f32),
+    /// `0xRRGGBB` color.
     Hex(u32),
     Hsl(u16, u8, u8),
 }
@@ -13,10 +23,484 @@ impl Display for Color {
         match self {
             Color::Named(name) => write!(fmt, "{}", name),
             Color::Rgb(r, g, b) => write!(fmt, "rgb({},{},{})", r, g, b),
-            Color::Hex(hex) => write!(fmt, "{:#X}", hex),
+            Color::Rgba(r, g, b, a) => write!(fmt, "rgba({},{},{},{})", r, g, b, a.clamp(0.0, 1.0)),
+            Color::Hex(hex) => write!(fmt, "#{:06X}", hex & 0xFF_FFFF),
             Color::Hsl(h, s, l) => {
                 write!(fmt, "hsl({},{}%,{}%)", h % 360, s.min(&100), l.min(&100))
             }
         }
     }
 }
+
+/// Keywords that aren't plain colors.
+const KEYWORDS: &[&str] = &["none", "transparent", "currentColor"];
+
+/// The CSS named colors and their `0xRRGGBB` values.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+/// Error returned when a string isn't a color [`Color::parse`] understands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseColorError(String);
+
+impl Display for ParseColorError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(fmt, "invalid color: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {}
+
+/// Parses the comma separated arguments of a css function such as `rgb(1,2,3)`, ignoring `%`.
+fn arguments<'a>(value: &'a str, function: &str) -> Option<Vec<&'a str>> {
+    Some(
+        value
+            .strip_prefix(function)?
+            .strip_prefix('(')?
+            .strip_suffix(')')?
+            .split(',')
+            .map(|argument| argument.trim().trim_end_matches('%'))
+            .collect(),
+    )
+}
+
+fn split_hex(hex: u32) -> (u8, u8, u8) {
+    ((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+}
+
+/// Converts a hue in degrees and a saturation and lightness in percents to rgb components.
+fn hsl_to_rgb(h: u16, s: u8, l: u8) -> (u8, u8, u8) {
+    let h = (h % 360) as f32 / 60.0;
+    let s = s.min(100) as f32 / 100.0;
+    let l = l.min(100) as f32 / 100.0;
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let component = |value: f32| ((value + l - chroma / 2.0) * 255.0).round() as u8;
+    (component(r), component(g), component(b))
+}
+
+impl Color {
+    /// Parses a CSS named color, `rgb(r,g,b)`, `rgba(r,g,b,a)`, `hsl(h,s%,l%)` or a `#RGB`,
+    /// `#RRGGBB`, `#RRGGBBAA` or `0xRRGGBB` hexadecimal color.
+    pub fn parse(value: &str) -> core::result::Result<Self, ParseColorError> {
+        let value = value.trim();
+        let error = || ParseColorError(value.to_string());
+        let keywords = KEYWORDS.iter().copied();
+        let names = NAMED_COLORS.iter().map(|(name, _)| *name);
+        if let Some(name) = keywords
+            .chain(names)
+            .find(|name| name.eq_ignore_ascii_case(value))
+        {
+            return Ok(Color::Named(name));
+        }
+        if let Some(hex) = value
+            .strip_prefix('#')
+            .or_else(|| value.strip_prefix("0x"))
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            let has_alpha = hex.len() == 8;
+            let hex: String = match hex.len() {
+                3 => hex.chars().flat_map(|digit| [digit, digit]).collect(),
+                6 | 8 => hex.to_string(),
+                _ => return Err(error()),
+            };
+            let hex = u32::from_str_radix(&hex, 16).map_err(|_| error())?;
+            return Ok(if has_alpha {
+                let (r, g, b) = split_hex(hex >> 8);
+                Color::Rgba(r, g, b, (hex & 0xFF) as f32 / 255.0)
+            } else {
+                Color::Hex(hex)
+            });
+        }
+        let number = |argument: &str| argument.parse::<u8>().map_err(|_| error());
+        if let Some(arguments) = arguments(value, "rgb") {
+            if let [r, g, b] = arguments.as_slice() {
+                return Ok(Color::Rgb(number(r)?, number(g)?, number(b)?));
+            }
+        }
+        if let Some(arguments) = arguments(value, "rgba") {
+            if let [r, g, b, a] = arguments.as_slice() {
+                let a = a.parse().map_err(|_| error())?;
+                return Ok(Color::Rgba(number(r)?, number(g)?, number(b)?, a));
+            }
+        }
+        if let Some(arguments) = arguments(value, "hsl") {
+            if let [h, s, l] = arguments.as_slice() {
+                let h = h.parse().map_err(|_| error())?;
+                return Ok(Color::Hsl(h, number(s)?, number(l)?));
+            }
+        }
+        Err(error())
+    }
+
+    /// Red, green, blue and alpha components of the color, `None` for the `none` and
+    /// `currentColor` keywords which have no value of their own.
+    pub fn to_rgba(&self) -> Option<(u8, u8, u8, f32)> {
+        let opaque = |(r, g, b): (u8, u8, u8)| Some((r, g, b, 1.0));
+        match *self {
+            Color::Named(name) if name.eq_ignore_ascii_case("transparent") => Some((0, 0, 0, 0.0)),
+            Color::Named(name) => NAMED_COLORS
+                .iter()
+                .find(|(named, _)| named.eq_ignore_ascii_case(name))
+                .and_then(|(_, hex)| opaque(split_hex(*hex))),
+            Color::Rgb(r, g, b) => opaque((r, g, b)),
+            Color::Rgba(r, g, b, a) => Some((r, g, b, a.clamp(0.0, 1.0))),
+            Color::Hex(hex) => opaque(split_hex(hex)),
+            Color::Hsl(h, s, l) => opaque(hsl_to_rgb(h, s, l)),
+        }
+    }
+
+    /// Color from components, `Rgb` when opaque.
+    fn from_rgba((r, g, b, a): (u8, u8, u8, f32)) -> Self {
+        if a >= 1.0 {
+            Color::Rgb(r, g, b)
+        } else {
+            Color::Rgba(r, g, b, a)
+        }
+    }
+
+    /// Blends the color towards `other`, `amount` going from 0 (this color) to 1 (`other`).
+    /// Keywords without a value, such as `none`, are returned unchanged.
+    pub fn mix(&self, other: Color, amount: f32) -> Self {
+        match (self.to_rgba(), other.to_rgba()) {
+            (Some((r1, g1, b1, a1)), Some((r2, g2, b2, a2))) => {
+                let amount = amount.clamp(0.0, 1.0);
+                let blend = |from: u8, to: u8| {
+                    (from as f32 + (to as f32 - from as f32) * amount).round() as u8
+                };
+                Color::from_rgba((
+                    blend(r1, r2),
+                    blend(g1, g2),
+                    blend(b1, b2),
+                    a1 + (a2 - a1) * amount,
+                ))
+            }
+            _ => *self,
+        }
+    }
+
+    /// Blends the color towards white, keeping its alpha.
+    pub fn lighten(&self, amount: f32) -> Self {
+        self.with_alpha_of(self.mix(Color::Rgb(255, 255, 255), amount))
+    }
+
+    /// Blends the color towards black, keeping its alpha, e.g. to derive a stroke color from
+    /// a fill color.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.with_alpha_of(self.mix(Color::Rgb(0, 0, 0), amount))
+    }
+
+    /// The same color with the given alpha, between 0 (transparent) and 1 (opaque).
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        match self.to_rgba() {
+            Some((r, g, b, _)) => Color::Rgba(r, g, b, alpha.clamp(0.0, 1.0)),
+            None => *self,
+        }
+    }
+
+    fn with_alpha_of(&self, color: Color) -> Self {
+        match (self.to_rgba(), color.to_rgba()) {
+            (Some((_, _, _, a)), Some((r, g, b, _))) => Color::from_rgba((r, g, b, a)),
+            _ => color,
+        }
+    }
+}
+
+/// Colors blended between stops, to map a value such as a speed or an elevation to a color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorRamp {
+    stops: Vec<(f64, Color)>,
+}
+
+impl ColorRamp {
+    /// Ramp going through the color of every stop at its value, in any order.
+    pub fn new(stops: impl IntoIterator<Item = (f64, Color)>) -> Self {
+        let mut stops: Vec<(f64, Color)> = stops.into_iter().collect();
+        stops.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        Self { stops }
+    }
+
+    /// Color of the value, mixed from the two stops around it. Values outside the stops take
+    /// the color of the nearest one, and an empty ramp has no color.
+    pub fn color_at(&self, value: f64) -> Option<Color> {
+        let (first, last) = (self.stops.first()?, self.stops.last()?);
+        if value <= first.0 {
+            return Some(first.1);
+        }
+        if value >= last.0 {
+            return Some(last.1);
+        }
+        let upper = self.stops.iter().position(|(stop, _)| *stop >= value)?;
+        let ((from, from_color), (to, to_color)) = (self.stops[upper - 1], self.stops[upper]);
+        Some(from_color.mix(to_color, ((value - from) / (to - from)) as f32))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
+        Color::parse(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    /// Colors are stored as the CSS strings [`Color::parse`] reads back.
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Color::Hsl(h, s, l) => {
+                serializer.collect_str(&format_args!("hsl({},{}%,{}%)", h, s, l))
+            }
+            color => serializer.collect_str(color),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let value = <alloc::borrow::Cow<str>>::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorRamp};
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!("Red".parse(), Ok(Color::Named("red")));
+        assert_eq!("#ff8000".parse(), Ok(Color::Hex(0xFF8000)));
+        assert_eq!("#f80".parse(), Ok(Color::Hex(0xFF8800)));
+        assert_eq!("0xFF8000".parse(), Ok(Color::Hex(0xFF8000)));
+        assert_eq!("rgb(200, 0, 100)".parse(), Ok(Color::Rgb(200, 0, 100)));
+        assert_eq!("hsl(120,50%,25%)".parse(), Ok(Color::Hsl(120, 50, 25)));
+        assert!("reddish".parse::<Color>().is_err());
+        assert!("rgb(300,0,0)".parse::<Color>().is_err());
+        assert_eq!(
+            Color::parse("rgba(0,0,255,0.5)"),
+            Ok(Color::Rgba(0, 0, 255, 0.5))
+        );
+        assert_eq!(
+            Color::parse("#0000FF80"),
+            Ok(Color::Rgba(0, 0, 255, 128.0 / 255.0))
+        );
+        assert_eq!(
+            Color::parse("0x11223344"),
+            Ok(Color::Rgba(0x11, 0x22, 0x33, 0x44 as f32 / 255.0))
+        );
+    }
+
+    #[test]
+    fn test_color_ramp() {
+        let ramp = ColorRamp::new(vec![
+            (100.0, Color::Rgb(255, 0, 0)),
+            (0.0, Color::Rgb(0, 0, 255)),
+            (50.0, Color::Rgb(0, 255, 0)),
+        ]);
+        assert_eq!(ramp.color_at(-10.0), Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(ramp.color_at(25.0), Some(Color::Rgb(0, 128, 128)));
+        assert_eq!(ramp.color_at(50.0), Some(Color::Rgb(0, 255, 0)));
+        assert_eq!(ramp.color_at(200.0), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(ColorRamp::new(vec![]).color_at(1.0), None);
+    }
+
+    #[test]
+    fn test_color() {
+        assert_eq!(Color::Hex(0xFF).to_string(), "#0000FF");
+        assert_eq!(Color::Rgba(1, 2, 3, 0.5).to_string(), "rgba(1,2,3,0.5)");
+        assert_eq!(Color::Named("teal").to_rgba(), Some((0, 128, 128, 1.0)));
+        assert_eq!(Color::Named("none").to_rgba(), None);
+        assert_eq!(Color::Hsl(120, 100, 25).to_rgba(), Some((0, 128, 0, 1.0)));
+        assert_eq!(Color::Hsl(210, 50, 50).to_rgba(), Some((64, 128, 191, 1.0)));
+    }
+
+    #[test]
+    fn test_color_manipulation() {
+        let fill = Color::Rgb(100, 200, 50);
+        assert_eq!(fill.darken(0.2), Color::Rgb(80, 160, 40));
+        assert_eq!(fill.lighten(0.5), Color::Rgb(178, 228, 153));
+        assert_eq!(fill.with_alpha(0.5), Color::Rgba(100, 200, 50, 0.5));
+        assert_eq!(fill.with_alpha(0.5).darken(1.0), Color::Rgba(0, 0, 0, 0.5));
+        assert_eq!(
+            Color::Named("red").mix(Color::Hex(0x0000FF), 0.5),
+            Color::Rgb(128, 0, 128)
+        );
+        assert_eq!(Color::Named("none").darken(0.2), Color::Named("none"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_color() {
+        use serde::de::{value::Error, IntoDeserializer};
+        use serde::Deserialize;
+
+        let color = |value: &str| Color::deserialize(value.into_deserializer());
+        assert_eq!(color("navy"), Ok::<_, Error>(Color::Named("navy")));
+        assert_eq!(color("#00FF00"), Ok(Color::Hex(0x00FF00)));
+        assert!(color("nope").is_err());
+    }
+}
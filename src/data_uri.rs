@@ -0,0 +1,64 @@
+use crate::Svg;
+use alloc::string::{String, ToString};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn push_base64(output: &mut String, bytes: &[u8]) {
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+            group | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                output.push(BASE64[(group >> (18 - 6 * index)) as usize & 0x3f] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+}
+
+impl Svg<'_> {
+    /// Renders the document as a base64 `data:` URI, to be used as the `src` of an `<img>` or
+    /// in a CSS `url()`.
+    pub fn to_data_uri(&self) -> String {
+        let mut uri = String::from("data:image/svg+xml;base64,");
+        push_base64(&mut uri, self.to_string().as_bytes());
+        uri
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::push_base64;
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    fn base64(bytes: &[u8]) -> String {
+        let mut output = String::new();
+        push_base64(&mut output, bytes);
+        output
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xff, 0xfe, 0x80]), "//6A");
+    }
+
+    #[test]
+    fn test_to_data_uri() {
+        let point = Point::new(1.0, 2.0);
+        let svg = point.to_svg();
+        let uri = svg.to_data_uri();
+        assert!(uri.starts_with("data:image/svg+xml;base64,PHN2ZyB4bWxucz0i"));
+        assert_eq!(
+            &uri["data:image/svg+xml;base64,".len()..],
+            base64(svg.to_string().as_bytes())
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::number::Num;
+use crate::svg::extent_rect;
+use crate::{Style, ToSvgStr, ViewBox};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+use geo_types::{Coord, CoordNum, LineString, Point, Polygon};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+/// Vertices of the ring without the closing one.
+fn open_ring<T: CoordNum>(ring: &LineString<T>) -> &[Coord<T>] {
+    match (ring.is_closed(), ring.0.split_last()) {
+        (true, Some((_, open))) if !open.is_empty() => open,
+        _ => &ring.0[..],
+    }
+}
+
+/// Dot and index of every vertex of the rings, numbered from 0 in each ring. The closing
+/// vertex of a closed ring isn't marked again, so any other repeated index is a duplicate
+/// point. Markers are sized from the style radius.
+pub(crate) fn vertex_markers<'a, T: CoordNum + 'a>(
+    rings: impl IntoIterator<Item = &'a LineString<T>>,
+    style: &Style,
+) -> String {
+    if !style.debug_vertices {
+        return String::new();
+    }
+    let radius = style.radius;
+    let mut markers = String::new();
+    for ring in rings {
+        for (index, coord) in open_ring(ring).iter().enumerate() {
+            write!(
+                markers,
+                r#"<circle cx="{x}" cy="{y}" r="{r}"/><text x="{x}" y="{y}" dx="{r}" dy="-{r}">{index}</text>"#,
+                x = Num(coord.x),
+                y = Num(coord.y),
+                r = radius,
+                index = index,
+            )
+            .unwrap();
+        }
+    }
+    if markers.is_empty() {
+        return markers;
+    }
+    format!(
+        r#"<g class="debug-vertices" fill="red" font-size="{}">{}</g>"#,
+        radius * 3.0,
+        markers
+    )
+}
+
+/// Coordinates of every vertex of the rings with `precision` decimals, written below and to
+/// the right of it in a font twice the style radius high.
+pub(crate) fn coordinate_labels<'a, T: CoordNum + 'a>(
+    rings: impl IntoIterator<Item = &'a LineString<T>>,
+    style: &Style,
+) -> String {
+    let precision = match style.coordinate_labels {
+        Some(precision) => precision,
+        None => return String::new(),
+    };
+    let radius = style.radius;
+    let mut labels = String::new();
+    for ring in rings {
+        for coord in open_ring(ring) {
+            let (x, y): (f64, f64) = match (NumCast::from(coord.x), NumCast::from(coord.y)) {
+                (Some(x), Some(y)) => (x, y),
+                _ => continue,
+            };
+            write!(
+                labels,
+                r#"<text x="{x}" y="{y}" dx="{r}" dy="{r}">{x_value:.precision$}, {y_value:.precision$}</text>"#,
+                x = Num(x),
+                y = Num(y),
+                r = radius,
+                x_value = x,
+                y_value = y,
+                precision = precision,
+            )
+            .unwrap();
+        }
+    }
+    if labels.is_empty() {
+        return labels;
+    }
+    format!(
+        r#"<g class="coordinate-labels" dominant-baseline="hanging" font-size="{}">{}</g>"#,
+        radius * 2.0,
+        labels
+    )
+}
+
+/// Dashed outline of the extent of a geometry, one pixel wide whatever the zoom level.
+pub(crate) fn bbox_rect(viewbox: &ViewBox) -> String {
+    if viewbox.is_empty() {
+        return String::new();
+    }
+    extent_rect(
+        viewbox,
+        r#" class="debug-bbox" fill="none" stroke="blue" stroke-width="1" stroke-dasharray="4 2" vector-effect="non-scaling-stroke""#,
+    )
+}
+
+fn coords_f64<T: CoordNum>(line_string: &LineString<T>) -> Vec<(f64, f64)> {
+    line_string
+        .coords()
+        .filter_map(|coord| Some((NumCast::from(coord.x)?, NumCast::from(coord.y)?)))
+        .collect()
+}
+
+/// Center of mass of the line, the midpoints of its segments weighted by their length.
+pub(crate) fn line_string_centroid<T: CoordNum>(line_string: &LineString<T>) -> Option<(f64, f64)> {
+    let coords = coords_f64(line_string);
+    let (mut length, mut x, mut y) = (0.0, 0.0, 0.0);
+    for segment in coords.windows(2) {
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        let segment_length = (x1 - x0).hypot(y1 - y0);
+        length += segment_length;
+        x += (x0 + x1) / 2.0 * segment_length;
+        y += (y0 + y1) / 2.0 * segment_length;
+    }
+    if length == 0.0 {
+        coords.first().copied()
+    } else {
+        Some((x / length, y / length))
+    }
+}
+
+/// Center of mass of the polygon surface, holes removed whatever the winding of the rings.
+/// Polygons without area fall back to the centroid of their exterior.
+pub(crate) fn polygon_centroid<T: CoordNum>(polygon: &Polygon<T>) -> Option<(f64, f64)> {
+    let (mut area, mut x, mut y) = (0.0, 0.0, 0.0);
+    for (index, ring) in core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .enumerate()
+    {
+        let coords = coords_f64(ring);
+        let (mut ring_area, mut ring_x, mut ring_y) = (0.0, 0.0, 0.0);
+        for (a, b) in coords.iter().zip(coords.iter().cycle().skip(1)) {
+            let cross = a.0 * b.1 - b.0 * a.1;
+            ring_area += cross / 2.0;
+            ring_x += (a.0 + b.0) * cross / 6.0;
+            ring_y += (a.1 + b.1) * cross / 6.0;
+        }
+        // the moments have the sign of the ring area, which is made negative for holes
+        let sign = if (ring_area < 0.0) == (index == 0) {
+            -1.0
+        } else {
+            1.0
+        };
+        area += sign * ring_area;
+        x += sign * ring_x;
+        y += sign * ring_y;
+    }
+    if area == 0.0 {
+        line_string_centroid(polygon.exterior())
+    } else {
+        Some((x / area, y / area))
+    }
+}
+
+/// Point drawn on `centroid` with the centroid marker style, if any.
+pub(crate) fn centroid_marker(centroid: Option<(f64, f64)>, style: &Style) -> String {
+    match (centroid, &style.centroid_marker) {
+        (Some((x, y)), Some(marker_style)) => Point::new(x, y).to_svg_str(marker_style),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bbox_rect, coordinate_labels, line_string_centroid, polygon_centroid, vertex_markers,
+    };
+    use crate::{Style, ViewBox};
+    use geo_types::{LineString, Polygon};
+
+    #[test]
+    fn test_vertex_markers() {
+        let style = Style {
+            debug_vertices: true,
+            ..Style::default()
+        };
+        let ring = LineString::from(vec![(0, 0), (2, 0), (2, 2), (0, 0)]);
+        assert_eq!(
+            vertex_markers(Some(&ring), &style),
+            r#"<g class="debug-vertices" fill="red" font-size="3"><circle cx="0" cy="0" r="1"/><text x="0" y="0" dx="1" dy="-1">0</text><circle cx="2" cy="0" r="1"/><text x="2" y="0" dx="1" dy="-1">1</text><circle cx="2" cy="2" r="1"/><text x="2" y="2" dx="1" dy="-1">2</text></g>"#
+        );
+        assert_eq!(vertex_markers(Some(&ring), &Style::default()), "");
+    }
+
+    #[test]
+    fn test_centroids() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0)]);
+        let centroid = line_string_centroid(&line_string).unwrap();
+        assert_eq!(centroid, (8.0 / 3.0, 1.0 / 3.0));
+        assert_eq!(
+            line_string_centroid(&LineString::from(vec![(1.0, 1.0)])),
+            Some((1.0, 1.0))
+        );
+        assert_eq!(line_string_centroid(&LineString::<f64>::new(vec![])), None);
+
+        // a 4x4 square with a 2x2 hole in its right half, rings wound the same way
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]),
+            vec![LineString::from(vec![
+                (2.0, 1.0),
+                (4.0, 1.0),
+                (4.0, 3.0),
+                (2.0, 3.0),
+            ])],
+        );
+        let (x, y) = polygon_centroid(&polygon).unwrap();
+        assert!((x - 5.0 / 3.0).abs() < 1e-12);
+        assert!((y - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_coordinate_labels() {
+        let style = Style {
+            coordinate_labels: Some(1),
+            ..Style::default()
+        };
+        let line_string = LineString::from(vec![(0.0, 0.25), (2.5, -1.0)]);
+        assert_eq!(
+            coordinate_labels(Some(&line_string), &style),
+            r#"<g class="coordinate-labels" dominant-baseline="hanging" font-size="2"><text x="0.0" y="0.25" dx="1" dy="1">0.0, 0.2</text><text x="2.5" y="-1.0" dx="1" dy="1">2.5, -1.0</text></g>"#
+        );
+        assert_eq!(coordinate_labels(Some(&line_string), &Style::default()), "");
+    }
+
+    #[test]
+    fn test_bbox_rect() {
+        assert_eq!(
+            bbox_rect(&ViewBox::new(-1.0, 0.0, 2.0, 4.0)),
+            r#"<rect x="-1" y="0" width="3" height="4" class="debug-bbox" fill="none" stroke="blue" stroke-width="1" stroke-dasharray="4 2" vector-effect="non-scaling-stroke"/>"#
+        );
+        assert_eq!(bbox_rect(&ViewBox::default()), "");
+    }
+}
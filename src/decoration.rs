@@ -0,0 +1,441 @@
+use crate::marker::round;
+use crate::{Color, DominantBaseline, FontWeight, Label, Style, TextAnchor, ViewBox};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Write;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// Corner of the viewbox where a decoration is placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Corner {
+    /// Position of the top left corner of a `width` by `height` box in this corner of the
+    /// viewbox, `margin` away from its edges.
+    fn place(&self, viewbox: &ViewBox, width: f64, height: f64, margin: f64) -> (f64, f64) {
+        let (min_x, min_y) = (viewbox.min_x(), viewbox.min_y());
+        let (max_x, max_y) = (viewbox.max_x(), viewbox.max_y());
+        let x = match self {
+            Corner::TopLeft | Corner::BottomLeft => min_x + margin,
+            Corner::TopRight | Corner::BottomRight => max_x - margin - width,
+        };
+        let y = match self {
+            Corner::TopLeft | Corner::TopRight => min_y + margin,
+            Corner::BottomLeft | Corner::BottomRight => max_y - margin - height,
+        };
+        (x, y)
+    }
+}
+
+/// Bar of alternating dark and light segments with distance labels, sized to a round
+/// distance of about a quarter of the width of the final viewbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleBar {
+    /// Distance represented by one unit of the svg user space, i.e. one map unit.
+    pub units_per_px: f64,
+    /// Name of the distance unit, written after the last label.
+    pub unit: String,
+    pub segments: usize,
+    pub corner: Corner,
+    pub dark: Color,
+    pub light: Color,
+}
+
+impl ScaleBar {
+    pub fn new(units_per_px: f64, unit: &str) -> Self {
+        Self {
+            units_per_px,
+            unit: unit.into(),
+            segments: 4,
+            corner: Corner::BottomLeft,
+            dark: Color::Named("black"),
+            light: Color::Named("white"),
+        }
+    }
+
+    pub fn with_segments(mut self, segments: usize) -> Self {
+        self.segments = segments.max(1);
+        self
+    }
+
+    pub fn with_corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    pub fn with_colors(mut self, dark: Color, light: Color) -> Self {
+        self.dark = dark;
+        self.light = light;
+        self
+    }
+
+    pub(crate) fn to_svg_str(&self, viewbox: &ViewBox) -> String {
+        let width = viewbox.width();
+        let distance = match nice_distance(width * 0.25 * self.units_per_px) {
+            Some(distance) => distance,
+            None => return String::new(),
+        };
+        let length = distance / self.units_per_px;
+        let bar_height = width * 0.015;
+        let font_size = bar_height * 2.0;
+        let label_height = font_size * 1.2;
+        let (x, y) = self
+            .corner
+            .place(viewbox, length, label_height + bar_height, width * 0.03);
+        let bar_y = y + label_height;
+        let segment_length = length / self.segments as f64;
+        let label_style = Style {
+            font_size: Some(font_size as f32),
+            dominant_baseline: Some(DominantBaseline::Auto),
+            fill: Some(self.dark),
+            ..Style::default()
+        };
+
+        let mut svg = String::from(r#"<g class="scale-bar">"#);
+        for i in 0..self.segments {
+            write!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{width}" height="{height}" fill="{fill}" stroke="{stroke}" stroke-width="{stroke_width}"/>"#,
+                x = round(x + i as f64 * segment_length),
+                y = round(bar_y),
+                width = round(segment_length),
+                height = round(bar_height),
+                fill = if i % 2 == 0 { self.dark } else { self.light },
+                stroke = self.dark,
+                stroke_width = round(bar_height / 10.0),
+            )
+            .unwrap();
+        }
+        for i in 0..=self.segments {
+            let value = round(distance * i as f64 / self.segments as f64);
+            let text = if i == self.segments {
+                format!("{} {}", value, self.unit)
+            } else {
+                value.to_string()
+            };
+            let label_x = round(x + i as f64 * segment_length);
+            let label_y = round(bar_y - bar_height / 2.0);
+            svg.push_str(&Label::new(label_x, label_y, &text, &label_style).to_string());
+        }
+        svg.push_str("</g>");
+        svg
+    }
+}
+
+/// Built-in designs of [`NorthArrow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NorthArrowDesign {
+    /// Filled arrowhead.
+    Arrow,
+    /// Arrowhead with a dark left half and a light right half.
+    Split,
+    /// Four pointed compass star with a dark north point.
+    Compass,
+}
+
+/// Arrow pointing to the north with an `N` above it, placed in a corner of the final viewbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NorthArrow {
+    pub design: NorthArrowDesign,
+    /// Height of the arrow in map units, a tenth of the smallest side of the viewbox if unset.
+    pub size: Option<f64>,
+    pub corner: Corner,
+    /// Clockwise rotation in degrees, e.g. to account for grid convergence.
+    pub rotation: f64,
+    pub dark: Color,
+    pub light: Color,
+}
+
+impl NorthArrow {
+    pub fn new(design: NorthArrowDesign) -> Self {
+        Self {
+            design,
+            size: None,
+            corner: Corner::TopRight,
+            rotation: 0.0,
+            dark: Color::Named("black"),
+            light: Color::Named("white"),
+        }
+    }
+
+    pub fn with_size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn with_colors(mut self, dark: Color, light: Color) -> Self {
+        self.dark = dark;
+        self.light = light;
+        self
+    }
+
+    /// Paths of the design in a unit box centered on the origin, the arrow below the `N`.
+    fn paths(&self) -> Vec<(String, Color)> {
+        match self.design {
+            NorthArrowDesign::Arrow => {
+                vec![("M0 -0.15L0.25 0.5L0 0.3L-0.25 0.5z".into(), self.dark)]
+            }
+            NorthArrowDesign::Split => vec![
+                ("M0 -0.15L-0.25 0.5L0 0.3z".into(), self.dark),
+                ("M0 -0.15L0.25 0.5L0 0.3z".into(), self.light),
+            ],
+            NorthArrowDesign::Compass => (0..4)
+                .map(|quarter| {
+                    let (sin, cos) = (quarter as f64 * core::f64::consts::FRAC_PI_2).sin_cos();
+                    // point of the star pointing up, rotated around the center of the star
+                    let rotate = |x: f64, y: f64| {
+                        (round(x * cos - y * sin), round(x * sin + y * cos + 0.175))
+                    };
+                    let (tip, left, right) =
+                        (rotate(0.0, -0.325), rotate(-0.08, 0.0), rotate(0.08, 0.0));
+                    let path = format!(
+                        "M{} {}L{} {}L{} {}z",
+                        tip.0, tip.1, right.0, right.1, left.0, left.1
+                    );
+                    (path, if quarter == 0 { self.dark } else { self.light })
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn to_svg_str(&self, viewbox: &ViewBox) -> String {
+        let (width, height) = (viewbox.width(), viewbox.height());
+        let size = self.size.unwrap_or(width.min(height) * 0.1);
+        let (x, y) = self.corner.place(viewbox, size, size, width * 0.03);
+        let mut svg = format!(
+            r#"<g class="north-arrow" transform="translate({x} {y}) rotate({rotation}) scale({size})">"#,
+            x = round(x + size / 2.0),
+            y = round(y + size / 2.0),
+            rotation = self.rotation,
+            size = round(size),
+        );
+        for (path, fill) in self.paths() {
+            write!(
+                svg,
+                r#"<path d="{path}" fill="{fill}" stroke="{stroke}" stroke-width="0.02" stroke-linejoin="round"/>"#,
+                path = path,
+                fill = fill,
+                stroke = self.dark,
+            )
+            .unwrap();
+        }
+        write!(
+            svg,
+            r#"<text x="0" y="-0.35" text-anchor="middle" dominant-baseline="central" font-size="0.3" fill="{}">N</text></g>"#,
+            self.dark
+        )
+        .unwrap();
+        svg
+    }
+}
+
+/// Box listing labeled style swatches, placed in a corner of the final viewbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legend {
+    pub title: Option<String>,
+    pub entries: Vec<(String, Style)>,
+    pub corner: Corner,
+    /// Side of the swatches in map units, 3% of the viewbox width if unset.
+    pub swatch_size: Option<f64>,
+}
+
+impl Legend {
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            entries: vec![],
+            corner: Corner::BottomRight,
+            swatch_size: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds a swatch drawn with the fill and stroke of `style`.
+    pub fn with_entry(mut self, label: &str, style: &Style) -> Self {
+        self.entries.push((label.into(), style.clone()));
+        self
+    }
+
+    pub fn with_corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    pub fn with_swatch_size(mut self, swatch_size: f64) -> Self {
+        self.swatch_size = Some(swatch_size);
+        self
+    }
+
+    pub(crate) fn to_svg_str(&self, viewbox: &ViewBox) -> String {
+        let width = viewbox.width();
+        let swatch = self.swatch_size.unwrap_or(width * 0.03);
+        let (padding, row_height) = (swatch * 0.25, swatch * 1.5);
+        let text_style = Style {
+            font_size: Some((swatch * 0.8) as f32),
+            text_anchor: Some(TextAnchor::Start),
+            ..Style::default()
+        };
+        let title_style = Style {
+            font_weight: Some(FontWeight::Bold),
+            ..text_style.clone()
+        };
+        let title = self
+            .title
+            .as_ref()
+            .map(|title| Label::new(0.0, 0.0, title, &title_style));
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(label, style)| (Label::new(0.0, 0.0, label, &text_style), style))
+            .collect();
+        let title_height = if title.is_some() { row_height } else { 0.0 };
+        let content_width = entries
+            .iter()
+            .map(|(label, _)| swatch * 1.5 + label.width())
+            .chain(title.iter().map(Label::width))
+            .fold(0.0, f64::max);
+        let (box_width, box_height) = (
+            content_width + padding * 2.0,
+            title_height + entries.len() as f64 * row_height + padding * 2.0,
+        );
+        let (x, y) = self
+            .corner
+            .place(viewbox, box_width, box_height, width * 0.03);
+
+        let mut svg = format!(
+            r#"<g class="legend"><rect x="{x}" y="{y}" width="{width}" height="{height}" fill="white" stroke="black" stroke-width="{stroke_width}"/>"#,
+            x = round(x),
+            y = round(y),
+            width = round(box_width),
+            height = round(box_height),
+            stroke_width = round(swatch * 0.05),
+        );
+        let left = round(x + padding);
+        if let Some(mut title) = title {
+            title.x = left;
+            title.y = round(y + padding + row_height / 2.0);
+            svg.push_str(&title.to_string());
+        }
+        for (i, (mut label, style)) in entries.into_iter().enumerate() {
+            let center_y = y + padding + title_height + (i as f64 + 0.5) * row_height;
+            let swatch_style = Style {
+                id: None,
+                transform: None,
+                ..style.clone()
+            };
+            write!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{size}" height="{size}"{style}/>"#,
+                x = left,
+                y = round(center_y - swatch / 2.0),
+                size = round(swatch),
+                style = swatch_style,
+            )
+            .unwrap();
+            label.x = round(left + swatch * 1.5);
+            label.y = round(center_y);
+            svg.push_str(&label.to_string());
+        }
+        svg.push_str("</g>");
+        svg
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Largest 1, 2 or 5 times a power of ten which isn't above `max`.
+pub(crate) fn nice_distance(max: f64) -> Option<f64> {
+    if !max.is_finite() || max <= 0.0 {
+        return None;
+    }
+    let magnitude = 10f64.powf(max.log10().floor());
+    [5.0, 2.0, 1.0]
+        .iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|distance| *distance <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nice_distance, Legend, NorthArrow, NorthArrowDesign, ScaleBar};
+    use crate::{Color, Style, ToSvg};
+    use geo_types::Rect;
+
+    #[test]
+    fn test_scale_bar() {
+        assert_eq!(nice_distance(250.0), Some(200.0));
+        assert_eq!(nice_distance(0.07), Some(0.05));
+
+        let rect = Rect::new((0.0, 0.0), (98.0, 48.0));
+        let svg = rect
+            .to_svg()
+            .with_scale_bar(ScaleBar::new(10.0, "m"))
+            .to_string();
+        assert!(svg.contains(
+            r#"<g class="scale-bar"><rect x="2" y="44.5" width="5" height="1.5" fill="black" stroke="black" stroke-width="0.15"/>"#
+        ));
+        assert!(svg.contains(r#">200 m</text></g>"#));
+    }
+
+    #[test]
+    fn test_north_arrow() {
+        let rect = Rect::new((0.0, 0.0), (98.0, 48.0));
+        let svg = rect
+            .to_svg()
+            .with_north_arrow(NorthArrow::new(NorthArrowDesign::Compass).with_rotation(-2.5))
+            .to_string();
+        assert!(svg.contains(
+            r#"<g class="north-arrow" transform="translate(93.5 4.5) rotate(-2.5) scale(5)"><path d="M0 -0.15L0.08 0.175L-0.08 0.175z" fill="black""#
+        ));
+        assert_eq!(svg.matches("<path").count(), 4);
+    }
+
+    #[test]
+    fn test_legend() {
+        let forest = Style {
+            fill: Some(Color::Named("green")),
+            ..Style::default()
+        };
+        let rect = Rect::new((0.0, 0.0), (98.0, 48.0));
+        let svg = rect
+            .to_svg()
+            .with_legend(
+                Legend::new()
+                    .with_title("Land")
+                    .with_entry("Forest", &forest),
+            )
+            .to_string();
+        assert!(svg
+            .contains(r#"<g class="legend"><rect x="81.36" y="35.5" width="14.64" height="10.5""#));
+        assert!(svg.contains(r#"<rect x="82.11" y="41.5" width="3" height="3" fill="green"/>"#));
+        assert!(svg.contains(r#">Forest</text></g>"#));
+    }
+}
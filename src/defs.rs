@@ -0,0 +1,124 @@
+use crate::{Filter, Gradient, SymbolRegistry};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Result};
+
+/// Definitions collected while rendering a document, emitted once in its `<defs>`.
+#[derive(Debug, Default)]
+pub(crate) struct Defs {
+    symbols: SymbolRegistry,
+    clip_paths: Vec<String>,
+    /// Attributes and elements of the masks.
+    masks: Vec<(&'static str, String)>,
+    gradients: Vec<Gradient>,
+    filters: Vec<Filter>,
+}
+
+impl Defs {
+    pub fn new(symbols: SymbolRegistry) -> Self {
+        Self {
+            symbols,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a clip path made of the given elements and returns its id.
+    pub fn add_clip_path(&mut self, content: String) -> String {
+        self.clip_paths.push(content);
+        def_id("clip", self.clip_paths.len() - 1)
+    }
+
+    /// Adds a mask made of the given elements and returns its id.
+    pub fn add_mask(&mut self, content: String) -> String {
+        self.masks.push(("", content));
+        def_id("mask", self.masks.len() - 1)
+    }
+
+    /// Adds a mask painted with the gradient over the bounding box of the masked content and
+    /// returns its id.
+    pub fn add_gradient_mask(&mut self, gradient: Gradient) -> String {
+        self.gradients.push(gradient);
+        let content = format!(
+            r#"<rect width="1" height="1" fill="url(#{})"/>"#,
+            def_id("gradient", self.gradients.len() - 1)
+        );
+        self.masks
+            .push((r#" maskContentUnits="objectBoundingBox""#, content));
+        def_id("mask", self.masks.len() - 1)
+    }
+
+    /// Adds the filter unless an identical one was already added, and returns its id.
+    pub fn add_filter(&mut self, filter: Filter) -> String {
+        let index = match self.filters.iter().position(|other| *other == filter) {
+            Some(index) => index,
+            None => {
+                self.filters.push(filter);
+                self.filters.len() - 1
+            }
+        };
+        def_id("filter", index)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+            && self.clip_paths.is_empty()
+            && self.masks.is_empty()
+            && self.gradients.is_empty()
+            && self.filters.is_empty()
+    }
+}
+
+fn def_id(kind: &str, index: usize) -> String {
+    format!("{}-{}", kind, index)
+}
+
+/// Whether the id has the form of the ids generated for definitions, such as `clip-0` or
+/// `symbol-pin`, which another element can't have without breaking the references to them.
+pub(crate) fn is_generated_id(id: &str) -> bool {
+    let numbered = |kind: &str| {
+        id.strip_prefix(kind)
+            .and_then(|index| index.strip_prefix('-'))
+            .is_some_and(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+    };
+    ["clip", "mask", "gradient", "filter"]
+        .iter()
+        .any(|kind| numbered(kind))
+        || id.starts_with("symbol-")
+}
+
+impl Display for Defs {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        write!(fmt, "<defs>{}", self.symbols)?;
+        for (index, content) in self.clip_paths.iter().enumerate() {
+            write!(
+                fmt,
+                r#"<clipPath id="{id}">{content}</clipPath>"#,
+                id = def_id("clip", index),
+                content = content,
+            )?;
+        }
+        for (index, gradient) in self.gradients.iter().enumerate() {
+            gradient.write_element(fmt, &def_id("gradient", index))?;
+        }
+        for (index, (attributes, content)) in self.masks.iter().enumerate() {
+            write!(
+                fmt,
+                r#"<mask id="{id}"{attributes}>{content}</mask>"#,
+                id = def_id("mask", index),
+                attributes = attributes,
+                content = content,
+            )?;
+        }
+        for (index, filter) in self.filters.iter().enumerate() {
+            write!(
+                fmt,
+                r#"<filter id="{id}">{filter}</filter>"#,
+                id = def_id("filter", index),
+                filter = filter,
+            )?;
+        }
+        write!(fmt, "</defs>")
+    }
+}
@@ -0,0 +1,251 @@
+use crate::to_svg_str::{COORD_LEN, ELEMENT_LEN};
+use crate::{ColorRamp, Style, ToSvgStr, ViewBox};
+use alloc::{string::String, vec::Vec};
+use geo_types::{coord, CoordNum, Line, LineString, Point};
+use num_traits::NumCast;
+
+/// Points with a third coordinate, such as an elevation or a measure, which is drawn through
+/// the style: `mapper` gets the `x`, `y` and `z` of each point with the style of the svg and
+/// returns the style of the point, e.g. with a color or radius depending on `z`.
+///
+/// ```
+/// # use geo_svg::{Color, Style, ToSvg, ZPoints};
+/// let points = ZPoints::new(vec![(0.0, 0.0, 12.0), (5.0, 0.0, 850.0)], |_, _, z, style| {
+///     Style {
+///         fill: Some(if z > 500.0 { Color::Named("brown") } else { Color::Named("green") }),
+///         ..style.clone()
+///     }
+/// });
+/// assert!(points.to_svg().to_string().contains(r#"r="1" fill="brown"/>"#));
+/// ```
+#[derive(Clone)]
+pub struct ZPoints<F: Fn(f64, f64, f64, &Style) -> Style> {
+    pub coords: Vec<(f64, f64, f64)>,
+    pub mapper: F,
+}
+
+impl<F: Fn(f64, f64, f64, &Style) -> Style> ZPoints<F> {
+    pub fn new(coords: impl IntoIterator<Item = (f64, f64, f64)>, mapper: F) -> Self {
+        Self {
+            coords: coords.into_iter().collect(),
+            mapper,
+        }
+    }
+
+    fn styled_points<'a>(
+        &'a self,
+        style: &'a Style,
+    ) -> impl Iterator<Item = (Point<f64>, Style)> + 'a {
+        self.coords
+            .iter()
+            .map(move |&(x, y, z)| (Point::new(x, y), (self.mapper)(x, y, z, style)))
+    }
+}
+
+impl<F: Fn(f64, f64, f64, &Style) -> Style> ToSvgStr for ZPoints<F> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.styled_points(style)
+            .map(|(point, style)| point.to_svg_str(&style))
+            .collect()
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.styled_points(style)
+            .fold(ViewBox::default(), |viewbox, (point, style)| {
+                viewbox.add(&point.viewbox(&style))
+            })
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.coords.len() * (ELEMENT_LEN + COORD_LEN)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.coords
+            .iter()
+            .all(|(x, y, _)| x.is_finite() && y.is_finite())
+    }
+
+    fn uses_xlink(&self, style: &Style) -> bool {
+        self.styled_points(style)
+            .any(|(point, style)| point.uses_xlink(&style))
+    }
+
+    fn ids(&self, style: &Style) -> Vec<String> {
+        self.styled_points(style)
+            .flat_map(|(point, style)| point.ids(&style))
+            .collect()
+    }
+}
+
+/// Line string with a third coordinate, such as an elevation or a measure, which is drawn
+/// through the style: every segment is a separate line whose style `mapper` returns from the
+/// `x`, `y` and `z` of its middle, e.g. a track colored by its altitude. Setting a round
+/// `stroke-linecap` hides the seams between the segments.
+#[derive(Clone)]
+pub struct ZLineString<F: Fn(f64, f64, f64, &Style) -> Style> {
+    pub coords: Vec<(f64, f64, f64)>,
+    pub mapper: F,
+}
+
+impl<F: Fn(f64, f64, f64, &Style) -> Style> ZLineString<F> {
+    pub fn new(coords: impl IntoIterator<Item = (f64, f64, f64)>, mapper: F) -> Self {
+        Self {
+            coords: coords.into_iter().collect(),
+            mapper,
+        }
+    }
+
+    fn styled_segments<'a>(
+        &'a self,
+        style: &'a Style,
+    ) -> impl Iterator<Item = (Line<f64>, Style)> + 'a {
+        self.coords.windows(2).map(move |segment| {
+            let ((x1, y1, z1), (x2, y2, z2)) = (segment[0], segment[1]);
+            let line = Line::new(coord! { x: x1, y: y1 }, coord! { x: x2, y: y2 });
+            let style = (self.mapper)((x1 + x2) / 2.0, (y1 + y2) / 2.0, (z1 + z2) / 2.0, style);
+            (line, style)
+        })
+    }
+}
+
+impl<F: Fn(f64, f64, f64, &Style) -> Style> ToSvgStr for ZLineString<F> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.styled_segments(style)
+            .map(|(line, style)| line.to_svg_str(&style))
+            .collect()
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.styled_segments(style)
+            .fold(ViewBox::default(), |viewbox, (line, style)| {
+                viewbox.add(&line.viewbox(&style))
+            })
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.coords.len() * (ELEMENT_LEN + 2 * COORD_LEN)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.coords
+            .iter()
+            .all(|(x, y, _)| x.is_finite() && y.is_finite())
+    }
+
+    fn uses_xlink(&self, style: &Style) -> bool {
+        self.styled_segments(style)
+            .any(|(line, style)| line.uses_xlink(&style))
+    }
+
+    fn ids(&self, style: &Style) -> Vec<String> {
+        self.styled_segments(style)
+            .flat_map(|(line, style)| line.ids(&style))
+            .collect()
+    }
+}
+
+/// Line string colored by a value given for each vertex, such as a speed or an elevation:
+/// every segment is a separate line whose stroke color `ramp` gives for the mean value of its
+/// vertices, see [`ZLineString`]. Vertices past the end of `values` are left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentedLineString<T: CoordNum> {
+    pub line_string: LineString<T>,
+    pub values: Vec<f64>,
+    pub ramp: ColorRamp,
+}
+
+impl<T: CoordNum> SegmentedLineString<T> {
+    pub fn new(line_string: LineString<T>, values: Vec<f64>, ramp: ColorRamp) -> Self {
+        Self {
+            line_string,
+            values,
+            ramp,
+        }
+    }
+
+    fn segments(&self) -> ZLineString<impl Fn(f64, f64, f64, &Style) -> Style + '_> {
+        let f = |value: T| NumCast::from(value).unwrap_or(f64::NAN);
+        let coords = self
+            .line_string
+            .coords()
+            .zip(&self.values)
+            .map(|(coord, value)| (f(coord.x), f(coord.y), *value));
+        ZLineString::new(coords, move |_, _, value, style| Style {
+            stroke_color: self.ramp.color_at(value).or(style.stroke_color),
+            ..style.clone()
+        })
+    }
+}
+
+impl<T: CoordNum> ToSvgStr for SegmentedLineString<T> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.segments().to_svg_str(style)
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.segments().viewbox(style)
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.values.len() * (ELEMENT_LEN + 2 * COORD_LEN)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.segments().is_finite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SegmentedLineString, ZLineString, ZPoints};
+    use crate::{Color, ColorRamp, Style, ToSvg};
+    use geo_types::LineString;
+
+    fn width_by_z(_: f64, _: f64, z: f64, style: &Style) -> Style {
+        Style {
+            stroke_width: Some(z as f32),
+            radius: z as f32,
+            ..style.clone()
+        }
+    }
+
+    #[test]
+    fn test_z_points() {
+        let points = ZPoints::new(vec![(0.0, 0.0, 1.0), (10.0, 0.0, 3.0)], width_by_z);
+        let svg = points.to_svg();
+        assert_eq!(
+            svg.svg_str(),
+            r#"<circle cx="0.0" cy="0.0" r="1" stroke-width="1"/><circle cx="10.0" cy="0.0" r="3" stroke-width="3"/>"#
+        );
+        assert_eq!(svg.viewbox().max_x(), 16.0);
+    }
+
+    #[test]
+    fn test_z_line_string() {
+        let line_string = ZLineString::new(
+            vec![(0.0, 0.0, 1.0), (10.0, 0.0, 3.0), (10.0, 10.0, 5.0)],
+            width_by_z,
+        );
+        assert_eq!(
+            line_string.to_svg().svg_str(),
+            r#"<path d="M 0.0 0.0 L 10.0 0.0" stroke-width="2"/><path d="M 10.0 0.0 L 10.0 10.0" stroke-width="4"/>"#
+        );
+    }
+
+    #[test]
+    fn test_segmented_line_string() {
+        let track = SegmentedLineString::new(
+            LineString::from(vec![(0, 0), (10, 0), (10, 10), (20, 10)]),
+            vec![0.0, 10.0, 20.0],
+            ColorRamp::new(vec![
+                (0.0, Color::Rgb(0, 0, 0)),
+                (20.0, Color::Rgb(200, 0, 0)),
+            ]),
+        );
+        assert_eq!(
+            track.to_svg().svg_str(),
+            r#"<path d="M 0.0 0.0 L 10.0 0.0" stroke="rgb(50,0,0)"/><path d="M 10.0 0.0 L 10.0 10.0" stroke="rgb(150,0,0)"/>"#
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use core::fmt::{Display, Formatter, Result};
+
+/// Text written with the characters that have a meaning in xml replaced by entities, so that
+/// it can be used as element content as well as in the value of a quoted attribute.
+#[derive(Clone, Copy)]
+pub(crate) struct Escaped<'a>(pub &'a str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let mut rest = self.0;
+        while let Some(index) = rest.find(['<', '>', '&', '"', '\'']) {
+            fmt.write_str(&rest[..index])?;
+            let entity = match rest.as_bytes()[index] {
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'&' => "&amp;",
+                b'"' => "&quot;",
+                _ => "&apos;",
+            };
+            fmt.write_str(entity)?;
+            rest = &rest[index + 1..];
+        }
+        fmt.write_str(rest)
+    }
+}
+
+/// Whether `name` can be written as the name of an xml element or attribute: a letter, `_`
+/// or `:` followed by letters, digits, `-`, `.`, `_` or `:`, any non-ascii character
+/// included, so that it can't close the tag or add other attributes.
+pub(crate) fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let is_start = |c: char| c.is_ascii_alphabetic() || c == '_' || c == ':' || !c.is_ascii();
+    match chars.next() {
+        Some(first) if is_start(first) => {
+            chars.all(|c| is_start(c) || c.is_ascii_digit() || c == '-' || c == '.')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_xml_name, Escaped};
+
+    #[test]
+    fn test_escaped() {
+        assert_eq!(Escaped("plain text").to_string(), "plain text");
+        assert_eq!(
+            Escaped(r#"<b class="x">Tom & Jerry's</b>"#).to_string(),
+            "&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/b&gt;"
+        );
+        assert_eq!(Escaped("déjà &").to_string(), "déjà &amp;");
+    }
+
+    #[test]
+    fn test_is_xml_name() {
+        assert!(is_xml_name("data-source"));
+        assert!(is_xml_name("xlink:href"));
+        assert!(is_xml_name("_été.2"));
+        assert!(!is_xml_name(""));
+        assert!(!is_xml_name("2d"));
+        assert!(!is_xml_name(r#"x="1" onload="alert(1)""#));
+        assert!(!is_xml_name("a/>"));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Svg;
+
+impl Svg<'_> {
+    fn evcxr_content(&self) -> String {
+        format!(
+            "EVCXR_BEGIN_CONTENT image/svg+xml\n{}\nEVCXR_END_CONTENT",
+            self
+        )
+    }
+
+    /// Draws the document inline when it is the result of a cell of an
+    /// [evcxr](https://github.com/evcxr/evcxr) Jupyter notebook, which calls this method.
+    pub fn evcxr_display(&self) {
+        println!("{}", self.evcxr_content());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    #[test]
+    fn test_evcxr_content() {
+        let point = Point::new(10.0, 20.0);
+        let svg = point.to_svg();
+        assert_eq!(
+            svg.evcxr_content(),
+            format!(
+                "EVCXR_BEGIN_CONTENT image/svg+xml\n{}\nEVCXR_END_CONTENT",
+                svg
+            )
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::Svg;
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Formatter, Result};
+
+/// What [`Svg::try_to_string`] does with geometries having NaN or infinite coordinates, which
+/// would be written as `NaN` or `inf` in the document and spoil its viewbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCoordinates {
+    /// Leaves the invalid geometries out of the document.
+    SkipInvalid,
+    /// Returns an [`InvalidGeometryError`].
+    Error,
+    /// Panics with the [`InvalidGeometryError`].
+    Panic,
+}
+
+/// Some geometries have NaN or infinite coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidGeometryError {
+    /// Number of invalid geometries.
+    pub count: usize,
+}
+
+impl Display for InvalidGeometryError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(
+            fmt,
+            "{} geometries have NaN or infinite coordinates",
+            self.count
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGeometryError {}
+
+fn invalid_count(svg: &Svg) -> usize {
+    let items = svg.items.iter().filter(|item| !item.is_finite()).count();
+    items + svg.siblings.iter().map(invalid_count).sum::<usize>()
+}
+
+/// Copy of the svg without the invalid geometries.
+fn without_invalid<'a>(svg: &Svg<'a>) -> Svg<'a> {
+    let mut valid = svg.clone();
+    valid.items.retain(|item| item.is_finite());
+    valid.siblings = svg.siblings.iter().map(without_invalid).collect();
+    valid
+}
+
+impl Svg<'_> {
+    /// Renders the document like its [`Display`] implementation, handling the geometries with
+    /// NaN or infinite coordinates according to `policy`. Geometries given to [`ToSvg::to_svg`]
+    /// together, such as the members of a collection, are kept or skipped together.
+    ///
+    /// [`ToSvg::to_svg`]: crate::ToSvg::to_svg
+    pub fn try_to_string(
+        &self,
+        policy: InvalidCoordinates,
+    ) -> core::result::Result<String, InvalidGeometryError> {
+        let count = invalid_count(self);
+        if count == 0 {
+            return Ok(self.to_string());
+        }
+        let error = InvalidGeometryError { count };
+        match policy {
+            InvalidCoordinates::SkipInvalid => Ok(without_invalid(self).to_string()),
+            InvalidCoordinates::Error => Err(error),
+            InvalidCoordinates::Panic => panic!("{}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidCoordinates, InvalidGeometryError};
+    use crate::ToSvg;
+    use geo_types::{LineString, Point};
+
+    #[test]
+    fn test_try_to_string() {
+        let point = Point::new(1.0, 2.0);
+        let nan = Point::new(f64::NAN, 0.0);
+        let line_string = LineString::from(vec![(0.0, 0.0), (f64::INFINITY, 1.0)]);
+        let svg = point.to_svg().and(nan.to_svg()).and(line_string.to_svg());
+
+        assert_eq!(
+            svg.try_to_string(InvalidCoordinates::Error),
+            Err(InvalidGeometryError { count: 2 })
+        );
+        assert_eq!(
+            svg.try_to_string(InvalidCoordinates::SkipInvalid).unwrap(),
+            point.to_svg().to_string()
+        );
+        assert_eq!(
+            point.to_svg().try_to_string(InvalidCoordinates::Error),
+            Ok(point.to_svg().to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "1 geometries have NaN or infinite coordinates")]
+    fn test_try_to_string_panic() {
+        let nan = Point::new(f64::NAN, 0.0);
+        let _ = nan.to_svg().try_to_string(InvalidCoordinates::Panic);
+    }
+}
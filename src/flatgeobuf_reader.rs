@@ -0,0 +1,61 @@
+use crate::{RenderOptions, Style, SvgProcessor};
+use flatgeobuf::FgbReader;
+use geozero::error::{GeozeroError, Result};
+use std::io::{Read, Seek, Write};
+
+/// Streams the features of a FlatGeobuf file intersecting the extent of `options` into a
+/// document drawing them with `style`, like [`render_iter`](crate::render_iter). Only the
+/// features found through the spatial index of the file are read, so a small part of a large
+/// file is rendered without reading the rest. Files without an index are an error.
+pub fn render_flatgeobuf<R: Read + Seek, W: Write>(
+    reader: R,
+    writer: W,
+    options: &RenderOptions,
+    style: &Style,
+) -> Result<()> {
+    let extent = &options.extent;
+    let mut features = FgbReader::open(reader)
+        .and_then(|reader| {
+            reader.select_bbox(
+                extent.min_x(),
+                extent.min_y(),
+                extent.max_x(),
+                extent.max_y(),
+            )
+        })
+        .map_err(|error| GeozeroError::Dataset(error.to_string()))?;
+    let mut processor = SvgProcessor::new(writer, options.clone(), style.clone());
+    features.process_features(&mut processor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_flatgeobuf;
+    use crate::{RenderOptions, Style, ViewBox};
+    use flatgeobuf::{FgbWriter, GeometryType};
+    use geo_types::{Geometry, Point};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_render_flatgeobuf() {
+        let mut fgb = FgbWriter::create("places", GeometryType::Point).unwrap();
+        for (x, y) in [(1.0, 1.0), (50.0, 50.0), (2.0, 3.0)] {
+            let point = Geometry::Point(Point::new(x, y));
+            fgb.add_feature_geom(point, |_| {}).unwrap();
+        }
+        let mut file = Vec::new();
+        fgb.write(&mut file).unwrap();
+
+        let options = RenderOptions::new(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+        let mut svg = Vec::new();
+        render_flatgeobuf(Cursor::new(&file), &mut svg, &options, &Style::default()).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert!(svg.contains(r#"cx="2.0" cy="3.0""#));
+        assert!(!svg.contains(r#"cx="50.0""#));
+        assert!(svg.ends_with("</svg>"));
+
+        let invalid = Cursor::new(b"not a flatgeobuf".to_vec());
+        assert!(render_flatgeobuf(invalid, Vec::new(), &options, &Style::default()).is_err());
+    }
+}
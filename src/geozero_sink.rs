@@ -0,0 +1,395 @@
+use crate::path::PathBuilder;
+use crate::projection::Project;
+use crate::stream::write_document_start;
+use crate::{ElementMode, RenderOptions, Style, ToSvgStr};
+use geo_types::{Coord, Point};
+use geozero::error::Result;
+use geozero::geo_types::GeoWriter;
+use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
+use std::io::Write;
+
+/// Sink writing the geometries of any [geozero](https://crates.io/crates/geozero) source,
+/// such as GeoJSON, WKB, FlatGeobuf or GDAL, into an svg document as they're read, like
+/// [`render_iter`](crate::render_iter) does: only the geometry being read is kept in memory.
+///
+/// Processing a dataset writes the whole document. When processing geometries on their own,
+/// the document is started by [`FeatureProcessor::dataset_begin`] and completed by
+/// [`FeatureProcessor::dataset_end`], which have to be called around them.
+///
+/// Points are drawn as they're read, and the path data of lines and polygons is written from
+/// their coordinates, unless the style needs whole geometries, e.g. to simplify or label them.
+/// Those are read into geo-types first.
+pub struct SvgProcessor<W: Write> {
+    writer: W,
+    options: RenderOptions,
+    style: Style,
+    /// The style is drawn from the coordinates alone, without reading whole geometries.
+    direct: bool,
+    /// Path data of the line string or polygon being read.
+    path: PathBuilder<f64>,
+    /// The coordinates being read are points, rather than a line string or ring.
+    points: bool,
+    /// The coordinates being read are rings of a polygon.
+    polygon: bool,
+    /// The next coordinate starts a line string or ring.
+    line_start: bool,
+    /// Geometry being read, when the style needs it whole.
+    geometry: GeoWriter,
+    /// Number of geometries begun and not ended yet, the geometry is written when it's back
+    /// to zero.
+    depth: usize,
+}
+
+/// Whether the elements of the style can be written without knowing the whole geometry:
+/// its coordinates are only transformed one by one and nothing is derived from them, such as
+/// labels or markers.
+fn draws_directly(style: &Style) -> bool {
+    #[cfg(feature = "geo")]
+    if style.simplify_epsilon.is_some() {
+        return false;
+    }
+    style.element_mode == ElementMode::Path
+        && style.smoothing.is_none()
+        && style.great_circle_step.is_none()
+        && !style.wrap_antimeridian
+        && style.snap_grid.is_none()
+        && !style.normalize_winding
+        && style.hole_style.is_none()
+        && style.label.is_none()
+        && style.area_label.is_none()
+        && style.text.is_none()
+        && style.direction_arrows.is_none()
+        && style.segment_lengths.is_none()
+        && !style.debug_vertices
+        && style.coordinate_labels.is_none()
+        && style.centroid_marker.is_none()
+}
+
+impl<W: Write> SvgProcessor<W> {
+    /// Processor drawing every geometry with `style`, in a document showing the extent of
+    /// `options`.
+    pub fn new(writer: W, options: RenderOptions, style: Style) -> Self {
+        let options = RenderOptions {
+            xlink_namespace: options.xlink_namespace || style.uses_xlink(),
+            ..options
+        };
+        Self {
+            writer,
+            options,
+            direct: draws_directly(&style),
+            path: PathBuilder::new(style.path_encoding),
+            points: false,
+            polygon: false,
+            line_start: false,
+            style,
+            geometry: GeoWriter::new(),
+            depth: 0,
+        }
+    }
+
+    /// Gives the writer back, once the document is written.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn begin(&mut self) {
+        self.depth += 1;
+    }
+
+    fn end(&mut self) -> Result<()> {
+        self.depth -= 1;
+        if self.depth == 0 {
+            if let Some(geometry) = self.geometry.take_geometry() {
+                self.writer
+                    .write_all(geometry.to_svg_str(&self.style).as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the element of the path data read so far, starting the next path.
+    fn write_path(&mut self, attributes: &str) -> Result<()> {
+        let path = std::mem::replace(&mut self.path, PathBuilder::new(self.style.path_encoding));
+        write!(
+            self.writer,
+            r#"<path{attributes} d="{d}"{style}/>"#,
+            attributes = attributes,
+            d = path.finish(),
+            style = self.style,
+        )?;
+        Ok(())
+    }
+
+    /// The coordinate as drawn, with its axes swapped and projected as the style asks.
+    fn transformed(&self, x: f64, y: f64) -> Coord<f64> {
+        let coord = match self.style.swap_axes {
+            true => Coord { x: y, y: x },
+            false => Coord { x, y },
+        };
+        match &self.style.projection {
+            Some(projection) => projection.project(coord),
+            None => coord,
+        }
+    }
+}
+
+impl<W: Write> GeomProcessor for SvgProcessor<W> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if !self.direct {
+            return self.geometry.xy(x, y, idx);
+        }
+        if self.points {
+            let point = Point::new(x, y).to_svg_str(&self.style);
+            self.writer.write_all(point.as_bytes())?;
+            return Ok(());
+        }
+        let coord = self.transformed(x, y);
+        if self.line_start {
+            self.path.move_to(coord);
+            self.line_start = false;
+        } else {
+            self.path.line_to(coord);
+        }
+        Ok(())
+    }
+
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            self.points = true;
+            return Ok(());
+        }
+        self.begin();
+        self.geometry.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            self.points = false;
+            return Ok(());
+        }
+        self.geometry.point_end(idx)?;
+        self.end()
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            self.points = true;
+            return Ok(());
+        }
+        self.begin();
+        self.geometry.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            self.points = false;
+            return Ok(());
+        }
+        self.geometry.multipoint_end(idx)?;
+        self.end()
+    }
+
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            self.line_start = true;
+            return Ok(());
+        }
+        if tagged {
+            self.begin();
+        }
+        self.geometry.linestring_begin(tagged, size, idx)
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.direct {
+            // a ring ends in its polygon, other line strings are elements of their own
+            if !self.polygon {
+                return self.write_path("");
+            }
+            if !self.line_start {
+                self.path.close();
+            }
+            return Ok(());
+        }
+        self.geometry.linestring_end(tagged, idx)?;
+        if tagged {
+            self.end()?;
+        }
+        Ok(())
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.begin();
+        self.geometry.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.geometry.multilinestring_end(idx)?;
+        self.end()
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            self.polygon = true;
+            return Ok(());
+        }
+        if tagged {
+            self.begin();
+        }
+        self.geometry.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.direct {
+            self.polygon = false;
+            return self.write_path(r#" fill-rule="evenodd""#);
+        }
+        self.geometry.polygon_end(tagged, idx)?;
+        if tagged {
+            self.end()?;
+        }
+        Ok(())
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.begin();
+        self.geometry.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.geometry.multipolygon_end(idx)?;
+        self.end()
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.begin();
+        self.geometry.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        if self.direct {
+            return Ok(());
+        }
+        self.geometry.geometrycollection_end(idx)?;
+        self.end()
+    }
+}
+
+impl<W: Write> PropertyProcessor for SvgProcessor<W> {}
+
+impl<W: Write> FeatureProcessor for SvgProcessor<W> {
+    fn dataset_begin(&mut self, _name: Option<&str>) -> Result<()> {
+        write_document_start(&mut self.writer, &self.options)?;
+        Ok(())
+    }
+
+    fn dataset_end(&mut self) -> Result<()> {
+        self.writer.write_all(b"</svg>")?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{draws_directly, SvgProcessor};
+    use crate::{render_iter, Color, PathEncoding, Projection, RenderOptions, Style};
+    use geo_types::{
+        line_string, polygon, Geometry, GeometryCollection, MultiLineString, MultiPoint,
+        MultiPolygon, Point,
+    };
+    use geozero::{FeatureProcessor, GeozeroGeometry};
+
+    /// Asserts that the processor writes the same document as `render_iter`.
+    fn assert_renders_like_iter(geometries: Vec<Geometry<f64>>, style: Style) {
+        let options = RenderOptions::from_features(
+            geometries
+                .iter()
+                .map(|geometry| (geometry.clone(), style.clone())),
+        );
+
+        let mut processor = SvgProcessor::new(Vec::new(), options.clone(), style.clone());
+        processor.dataset_begin(None).unwrap();
+        for geometry in &geometries {
+            geometry.process_geom(&mut processor).unwrap();
+        }
+        processor.dataset_end().unwrap();
+
+        let mut expected = Vec::new();
+        let features = geometries
+            .into_iter()
+            .map(|geometry| (geometry, style.clone()));
+        render_iter(features, &mut expected, &options).unwrap();
+        assert_eq!(
+            String::from_utf8(processor.into_inner()).unwrap(),
+            String::from_utf8(expected).unwrap()
+        );
+    }
+
+    fn geometries() -> Vec<Geometry<f64>> {
+        let square = polygon![
+            exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+        ];
+        let triangle = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 2.0)];
+        vec![
+            Geometry::Point(Point::new(1.0, 2.0)),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(3.0, 1.0), Point::new(0.5, 0.5)])),
+            Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 4.0, y: 1.0)]),
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0.0, y: 1.0), (x: 1.0, y: 3.0)],
+                line_string![(x: 2.0, y: 2.0), (x: 3.0, y: 0.0), (x: 1.0, y: 0.0)],
+            ])),
+            Geometry::MultiPolygon(MultiPolygon(vec![square, triangle.clone()])),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(triangle)])),
+        ]
+    }
+
+    #[test]
+    fn test_svg_processor() {
+        let style = Style {
+            stroke_color: Some(Color::Named("red")),
+            ..Style::default()
+        };
+        assert!(draws_directly(&style));
+        assert_renders_like_iter(geometries(), style);
+    }
+
+    #[test]
+    fn test_svg_processor_transformed_coordinates() {
+        let style = Style {
+            path_encoding: PathEncoding::Relative,
+            swap_axes: true,
+            projection: Some(Projection::WebMercator),
+            ..Style::default()
+        };
+        assert!(draws_directly(&style));
+        assert_renders_like_iter(geometries(), style);
+    }
+
+    #[test]
+    fn test_svg_processor_whole_geometries() {
+        let style = Style {
+            label: Some("A".into()),
+            debug_vertices: true,
+            ..Style::default()
+        };
+        assert!(!draws_directly(&style));
+        assert_renders_like_iter(geometries(), style);
+    }
+}
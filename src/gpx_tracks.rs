@@ -0,0 +1,70 @@
+use crate::{Color, Style, Styled};
+use geo_types::Geometry;
+use gpx::errors::GpxError;
+use gpx::Waypoint;
+use std::io::Read;
+
+fn line_style(css_class: &str, color: Color) -> Style {
+    Style {
+        fill: Some(Color::Named("none")),
+        stroke_color: Some(color),
+        stroke_width: Some(2.0),
+        css_classes: Some(css_class.into()),
+        ..Style::default()
+    }
+}
+
+fn waypoint(waypoint: &Waypoint) -> Styled<Geometry<f64>> {
+    let style = Style {
+        fill: Some(Color::Named("black")),
+        radius: 3.0,
+        css_classes: Some("gpx-waypoint".into()),
+        label: waypoint.name.clone(),
+        ..Style::default()
+    };
+    Styled::new(waypoint.point().into(), style)
+}
+
+/// Reads a GPX document into its tracks, routes and waypoints, in this order, ready to be
+/// rendered together. Tracks are red and routes blue lines, waypoints are dots labeled with
+/// their name. Every kind has a `gpx-track`, `gpx-route` or `gpx-waypoint` class, to restyle
+/// them with css.
+pub fn from_gpx<R: Read>(reader: R) -> Result<Vec<Styled<Geometry<f64>>>, GpxError> {
+    let gpx = gpx::read(reader)?;
+    let tracks = gpx.tracks.iter().map(|track| {
+        let style = line_style("gpx-track", Color::Named("red"));
+        Styled::new(track.multilinestring().into(), style)
+    });
+    let routes = gpx.routes.iter().map(|route| {
+        let style = line_style("gpx-route", Color::Named("blue"));
+        Styled::new(route.linestring().into(), style)
+    });
+    let waypoints = gpx.waypoints.iter().map(waypoint);
+    Ok(tracks.chain(routes).chain(waypoints).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_gpx;
+    use crate::ToSvg;
+
+    const GPX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="1.0" lon="2.0"><name>Summit</name></wpt>
+  <rte><rtept lat="0.0" lon="0.0"/><rtept lat="1.0" lon="1.0"/></rte>
+  <trk><trkseg><trkpt lat="0.0" lon="0.0"/><trkpt lat="0.5" lon="2.0"/></trkseg></trk>
+</gpx>"#;
+
+    #[test]
+    fn test_from_gpx() {
+        let features = from_gpx(GPX.as_bytes()).unwrap();
+        assert_eq!(features.len(), 3);
+        let svg = features.to_svg().to_string();
+        assert!(svg.contains(
+            r#"<path d="M 0.0 0.0 L 2.0 0.5" fill="none" stroke="red" stroke-width="2" class="gpx-track"/>"#
+        ));
+        assert!(svg.contains(r#"class="gpx-route""#));
+        assert!(svg.contains(">Summit</text>"));
+        assert!(from_gpx("<gpx".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Label, Style, ToSvgStr, ViewBox};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use geo_types::{LineString, MultiLineString};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// Grid of meridians and parallels, every `interval` degrees over `extent`, to be rendered as
+/// a layer with [`ToSvg::to_svg`](crate::ToSvg::to_svg).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graticule {
+    pub extent: ViewBox,
+    pub interval: f64,
+    /// Labels the meridians along the bottom edge and the parallels along the left edge.
+    pub labeled: bool,
+}
+
+impl Graticule {
+    pub fn new(extent: ViewBox, interval: f64) -> Self {
+        Self {
+            extent,
+            interval,
+            labeled: false,
+        }
+    }
+
+    pub fn with_labels(mut self) -> Self {
+        self.labeled = true;
+        self
+    }
+
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (
+            self.extent.min_x(),
+            self.extent.min_y().max(-90.0),
+            self.extent.max_x(),
+            self.extent.max_y().min(90.0),
+        )
+    }
+
+    pub fn meridians(&self) -> Vec<f64> {
+        let (min_x, _, max_x, _) = self.bounds();
+        multiples(self.interval, min_x, max_x)
+    }
+
+    pub fn parallels(&self) -> Vec<f64> {
+        let (_, min_y, _, max_y) = self.bounds();
+        multiples(self.interval, min_y, max_y)
+    }
+
+    pub fn lines(&self) -> MultiLineString<f64> {
+        let (min_x, min_y, max_x, max_y) = self.bounds();
+        let meridians = self
+            .meridians()
+            .into_iter()
+            .map(|x| LineString::from(vec![(x, min_y), (x, max_y)]));
+        let parallels = self
+            .parallels()
+            .into_iter()
+            .map(|y| LineString::from(vec![(min_x, y), (max_x, y)]));
+        MultiLineString(meridians.chain(parallels).collect())
+    }
+}
+
+/// Multiples of `interval` within `min..=max`.
+pub(crate) fn multiples(interval: f64, min: f64, max: f64) -> Vec<f64> {
+    if interval <= 0.0 || min > max {
+        return vec![];
+    }
+    let first = (min / interval).ceil() as i64;
+    let last = (max / interval).floor() as i64;
+    (first..=last).map(|i| i as f64 * interval).collect()
+}
+
+fn format_degrees(value: f64, positive: char, negative: char) -> String {
+    if value > 0.0 {
+        format!("{}°{}", value, positive)
+    } else if value < 0.0 {
+        format!("{}°{}", -value, negative)
+    } else {
+        "0°".into()
+    }
+}
+
+impl ToSvgStr for Graticule {
+    fn to_svg_str(&self, style: &Style) -> String {
+        let line_style = Style {
+            label: None,
+            ..style.clone()
+        };
+        let labels: String = self.labels(style).iter().map(ToString::to_string).collect();
+        self.lines().to_svg_str(&line_style) + &labels
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.lines().viewbox(style)
+    }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        if !self.labeled {
+            return vec![];
+        }
+        let (min_x, _, _, max_y) = self.bounds();
+        let meridians = self
+            .meridians()
+            .into_iter()
+            .map(|x| Label::new(x, max_y, &format_degrees(x, 'E', 'W'), style));
+        let parallels = self
+            .parallels()
+            .into_iter()
+            .map(|y| Label::new(min_x, y, &format_degrees(y, 'N', 'S'), style));
+        meridians.chain(parallels).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graticule;
+    use crate::{ToSvg, ViewBox};
+
+    #[test]
+    fn test_graticule() {
+        let graticule = Graticule::new(ViewBox::new(-15.0, 35.0, 25.0, 95.0), 10.0).with_labels();
+        assert_eq!(graticule.meridians(), vec![-10.0, 0.0, 10.0, 20.0]);
+        assert_eq!(
+            graticule.parallels(),
+            vec![40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+        );
+        let svg = graticule.to_svg().to_string();
+        assert!(svg.contains(r#"<path d="M -10.0 35.0 L -10.0 90.0"/>"#));
+        assert!(svg.contains(">10°W</text>"));
+        assert!(svg.contains(r#"<text x="-15.0" y="40.0" text-anchor="middle" dominant-baseline="central">40°N</text>"#));
+    }
+}
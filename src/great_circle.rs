@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+use geo_types::{Coord, CoordNum, LineString};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+fn to_radians<T: CoordNum>(coord: Coord<T>) -> (f64, f64) {
+    let x: f64 = NumCast::from(coord.x).unwrap_or(0.0);
+    let y: f64 = NumCast::from(coord.y).unwrap_or(0.0);
+    (x.to_radians(), y.to_radians())
+}
+
+/// Inserts points along the great circle between each pair of successive longitude/latitude
+/// coordinates, in degrees, so that no segment spans more than `step` degrees of arc.
+pub(crate) fn densify<T: CoordNum>(line_string: &LineString<T>, step: f64) -> LineString<f64> {
+    let mut coords = Vec::new();
+    for (index, line) in line_string.lines().enumerate() {
+        let (lon1, lat1) = to_radians(line.start);
+        let (lon2, lat2) = to_radians(line.end);
+        if index == 0 {
+            coords.push(Coord {
+                x: lon1.to_degrees(),
+                y: lat1.to_degrees(),
+            });
+        }
+        // haversine formula of the central angle
+        let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+        let angle = 2.0 * a.sqrt().min(1.0).asin();
+        // antipodal points have no single great circle between them
+        let pieces = if step > 0.0 && angle.sin().abs() > 1e-12 {
+            (angle.to_degrees() / step).ceil() as usize
+        } else {
+            1
+        };
+        for piece in 1..pieces {
+            let fraction = piece as f64 / pieces as f64;
+            let start_weight = ((1.0 - fraction) * angle).sin() / angle.sin();
+            let end_weight = (fraction * angle).sin() / angle.sin();
+            let x = start_weight * lat1.cos() * lon1.cos() + end_weight * lat2.cos() * lon2.cos();
+            let y = start_weight * lat1.cos() * lon1.sin() + end_weight * lat2.cos() * lon2.sin();
+            let z = start_weight * lat1.sin() + end_weight * lat2.sin();
+            coords.push(Coord {
+                x: y.atan2(x).to_degrees(),
+                y: z.atan2(x.hypot(y)).to_degrees(),
+            });
+        }
+        coords.push(Coord {
+            x: lon2.to_degrees(),
+            y: lat2.to_degrees(),
+        });
+    }
+    LineString(coords)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::densify;
+    use geo_types::LineString;
+
+    #[test]
+    fn test_densify() {
+        let equator = densify(&LineString::from(vec![(0.0, 0.0), (90.0, 0.0)]), 30.0);
+        let longitudes: Vec<_> = equator.coords().map(|c| c.x.round()).collect();
+        assert_eq!(longitudes, vec![0.0, 30.0, 60.0, 90.0]);
+
+        // the great circle from New York to Paris passes north of both
+        let flight = densify(&LineString::from(vec![(-74.0, 40.7), (2.35, 48.85)]), 5.0);
+        assert!(flight.coords().count() > 10);
+        assert!(flight.coords().any(|c| c.y > 51.0));
+    }
+}
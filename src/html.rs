@@ -0,0 +1,34 @@
+use crate::Svg;
+use alloc::format;
+use alloc::string::String;
+
+impl Svg<'_> {
+    /// Renders the document inline in a `<div>`, to be pasted into an html page. The drawing
+    /// takes the width of the page element holding it, never overflows it, and keeps its
+    /// aspect ratio. The xml namespace is kept so the snippet also works in xhtml.
+    pub fn to_html_snippet(&self) -> String {
+        let document = self.document(
+            None,
+            r#" style="display: block; width: 100%; height: auto;""#,
+        );
+        format!(
+            r#"<div class="geo-svg" style="max-width: 100%;">{}</div>"#,
+            document
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    #[test]
+    fn test_to_html_snippet() {
+        let point = Point::new(10.0, 20.0);
+        assert_eq!(
+            point.to_svg().with_radius(1.0).to_html_snippet(),
+            r#"<div class="geo-svg" style="max-width: 100%;"><svg style="display: block; width: 100%; height: auto;" xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="8 18 4 4"><circle cx="10.0" cy="20.0" r="1"/></svg></div>"#
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::aspect::PreserveAspectRatio;
+use crate::defs::Defs;
+use crate::{DominantBaseline, FontWeight, Label, Style, Svg, ViewBox};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// A map nested in a document, drawn in its own `<svg>` viewport.
+#[derive(Clone)]
+pub struct Inset<'a> {
+    pub svg: Svg<'a>,
+    /// Area of the outer document the map is fitted in.
+    pub area: ViewBox,
+    /// Title drawn centered above the area, and its font size.
+    pub title: Option<(String, f32)>,
+}
+
+impl<'a> Inset<'a> {
+    pub fn new(svg: Svg<'a>, x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            svg,
+            area: ViewBox::new(x as f64, y as f64, (x + width) as f64, (y + height) as f64),
+            title: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str, font_size: f32) -> Self {
+        self.title = Some((title.into(), font_size));
+        self
+    }
+
+    pub(crate) fn to_svg_str(&self, defs: &mut Defs) -> String {
+        let viewbox = self.svg.document_viewbox();
+        let (align, meet_or_slice) = self.svg.preserve_aspect_ratio;
+        let title = self
+            .title
+            .as_ref()
+            .map(|(title, font_size)| {
+                let style = Style {
+                    font_size: Some(*font_size),
+                    font_weight: Some(FontWeight::Bold),
+                    dominant_baseline: Some(DominantBaseline::TextAfterEdge),
+                    ..Style::default()
+                };
+                let x = self.area.min_x() + self.area.width() / 2.0;
+                let y = self.area.min_y() - *font_size as f64 * 0.25;
+                Label::new(x, y, title, &style).to_string()
+            })
+            .unwrap_or_default();
+        format!(
+            r#"{title}<svg x="{x}" y="{y}" width="{width}" height="{height}" preserveAspectRatio="{preserve_aspect_ratio}" viewBox="{min_x} {min_y} {w} {h}">{body}</svg>"#,
+            title = title,
+            preserve_aspect_ratio = PreserveAspectRatio(align, meet_or_slice),
+            x = self.area.min_x(),
+            y = self.area.min_y(),
+            width = self.area.width(),
+            height = self.area.height(),
+            min_x = viewbox.min_x(),
+            min_y = viewbox.min_y(),
+            w = viewbox.width(),
+            h = viewbox.height(),
+            body = self.svg.body(defs),
+        )
+    }
+}
+
+/// Small multiples: maps arranged in a grid of titled panels, e.g. to compare a region over
+/// several years.
+#[derive(Clone)]
+pub struct Facet<'a> {
+    pub panels: Vec<(Option<String>, Svg<'a>)>,
+    pub columns: usize,
+    pub panel_size: (f32, f32),
+    pub gap: f32,
+    pub title_size: f32,
+    /// All panels show the union of their extents at the same scale, instead of each one
+    /// being fitted to its own extent.
+    pub shared_scale: bool,
+}
+
+impl<'a> Facet<'a> {
+    pub fn new(columns: usize) -> Self {
+        Self {
+            panels: vec![],
+            columns: columns.max(1),
+            panel_size: (100.0, 100.0),
+            gap: 10.0,
+            title_size: 8.0,
+            shared_scale: false,
+        }
+    }
+
+    pub fn with_panel(mut self, title: Option<&str>, svg: Svg<'a>) -> Self {
+        self.panels.push((title.map(String::from), svg));
+        self
+    }
+
+    pub fn with_panel_size(mut self, width: f32, height: f32) -> Self {
+        self.panel_size = (width, height);
+        self
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_title_size(mut self, title_size: f32) -> Self {
+        self.title_size = title_size;
+        self
+    }
+
+    pub fn with_shared_scale(mut self) -> Self {
+        self.shared_scale = true;
+        self
+    }
+
+    /// Lays the panels out left to right and top to bottom, in a document whose units are
+    /// those of the panel size.
+    pub fn into_svg(self) -> Svg<'a> {
+        let (width, height) = self.panel_size;
+        let (columns, gap) = (self.columns, self.gap);
+        let has_titles = self.panels.iter().any(|(title, _)| title.is_some());
+        let title_height = if has_titles {
+            self.title_size * 1.5
+        } else {
+            0.0
+        };
+        let shared_viewbox = self
+            .panels
+            .iter()
+            .map(|(_, svg)| svg.document_viewbox())
+            .fold(ViewBox::default(), |viewbox, other| viewbox.add(&other));
+        let rows = self.panels.len().div_ceil(columns);
+        let used_columns = columns.min(self.panels.len());
+        let mut facet = Svg::new(vec![]);
+        for (index, (title, mut svg)) in self.panels.into_iter().enumerate() {
+            if self.shared_scale {
+                svg.custom_viewbox = Some(shared_viewbox);
+            }
+            let x = (index % columns) as f32 * (width + gap);
+            let y = (index / columns) as f32 * (height + title_height + gap) + title_height;
+            let mut inset = Inset::new(svg, x, y, width, height);
+            if let Some(title) = title {
+                inset = inset.with_title(&title, self.title_size);
+            }
+            facet.insets.push(inset);
+        }
+        let total = |count: usize, size: f32| (count as f32 * (size + gap) - gap).max(0.0);
+        facet.custom_viewbox = Some(ViewBox::new(
+            0.0,
+            0.0,
+            total(used_columns, width) as f64,
+            total(rows, height + title_height) as f64,
+        ));
+        facet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Facet;
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    #[test]
+    fn test_facet() {
+        let (before, after) = (Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+        let facet = Facet::new(2)
+            .with_panel(Some("2010"), before.to_svg())
+            .with_panel(Some("2020"), after.to_svg())
+            .with_panel(None, after.to_svg())
+            .with_shared_scale()
+            .into_svg()
+            .to_string();
+        assert!(facet.contains(r#"viewBox="0 0 210 234""#));
+        assert!(facet.contains(
+            r#"<text x="160.0" y="10.0" text-anchor="middle" dominant-baseline="text-after-edge" font-size="8" font-weight="bold">2020</text><svg x="110" y="12" width="100" height="100" preserveAspectRatio="xMidYMid meet" viewBox="-2 -2 14 14">"#
+        ));
+        assert!(facet.contains(r#"<svg x="0" y="134" width="100""#));
+    }
+}
@@ -0,0 +1,415 @@
+use crate::escape::Escaped;
+use crate::number::Num;
+use crate::style::{with_halo_copy, TextStyle};
+use crate::{DominantBaseline, Style, TextAnchor};
+use alloc::collections::BinaryHeap;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result};
+use geo_types::{CoordNum, LineString, Polygon};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+/// Font size assumed when estimating label extents, matching the SVG default.
+const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+/// Average glyph advance as a fraction of the font size, used to estimate label widths.
+const GLYPH_WIDTH_RATIO: f64 = 0.6;
+
+/// Spacing between the baselines of multi-line text, in `em`.
+pub(crate) const DEFAULT_LINE_HEIGHT: f32 = 1.2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelCollision {
+    /// Labels overlapping an already placed label are not rendered.
+    Drop,
+    /// Labels are moved around their anchor until they fit, and dropped if they can't.
+    Nudge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub x: f64,
+    pub y: f64,
+    pub text: String,
+    pub style: Style,
+}
+
+impl Label {
+    /// Labels are centered on their anchor point unless the style aligns them otherwise.
+    pub fn new(x: f64, y: f64, text: &str, style: &Style) -> Self {
+        Self {
+            x,
+            y,
+            text: text.into(),
+            style: Style {
+                text_anchor: style.text_anchor.or(Some(TextAnchor::Middle)),
+                dominant_baseline: style.dominant_baseline.or(Some(DominantBaseline::Central)),
+                ..style.clone()
+            },
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        let longest_line = self
+            .text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        longest_line as f64 * self.font_size() * GLYPH_WIDTH_RATIO
+    }
+
+    pub fn height(&self) -> f64 {
+        let extra_lines = self.text.lines().count().max(1) - 1;
+        let line_height = self.style.line_height.unwrap_or(DEFAULT_LINE_HEIGHT) as f64;
+        self.font_size() * (1.0 + extra_lines as f64 * line_height)
+    }
+
+    fn font_size(&self) -> f64 {
+        self.style
+            .font_size
+            .map_or(DEFAULT_FONT_SIZE, |font_size| font_size as f64)
+    }
+
+    fn bbox(&self) -> (f64, f64, f64, f64) {
+        let (width, height) = (self.width(), self.height());
+        let min_x = self.x - width * self.style.text_anchor.map_or(0.5, TextAnchor::offset);
+        let min_y = self.y
+            - height
+                * self
+                    .style
+                    .dominant_baseline
+                    .map_or(0.5, DominantBaseline::offset);
+        (min_x, min_y, min_x + width, min_y + height)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let (a_min_x, a_min_y, a_max_x, a_max_y) = self.bbox();
+        let (b_min_x, b_min_y, b_max_x, b_max_y) = other.bbox();
+        a_min_x < b_max_x && b_min_x < a_max_x && a_min_y < b_max_y && b_min_y < a_max_y
+    }
+}
+
+impl Display for Label {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let text = with_halo_copy(&self.style, |style| {
+            format!(
+                r#"<text{class} x="{x}" y="{y}"{text_style}>{text}</text>"#,
+                class = style
+                    .text_classes
+                    .as_deref()
+                    .map(|text_classes| format!(r#" class="{}""#, Escaped(text_classes)))
+                    .unwrap_or_default(),
+                x = Num(self.x),
+                y = Num(self.y),
+                text = text_content(&self.text, self.x, style),
+                text_style = TextStyle(style),
+            )
+        });
+        fmt.write_str(&text)
+    }
+}
+
+/// Escaped content of a `<text>` element, with one `<tspan>` per line when the text spans
+/// several.
+///
+/// The block of lines is shifted up according to the dominant baseline, so that it keeps the
+/// same alignment relative to `y` as a single line would.
+pub(crate) fn text_content<X: CoordNum>(text: &str, x: X, style: &Style) -> String {
+    if !text.contains('\n') {
+        return Escaped(text).to_string();
+    }
+    let line_height = style.line_height.unwrap_or(DEFAULT_LINE_HEIGHT);
+    let lines = text.lines().collect::<Vec<_>>();
+    let shift = style
+        .dominant_baseline
+        .map_or(0.0, DominantBaseline::offset) as f32;
+    let first_dy = 0.0 - (lines.len() - 1) as f32 * line_height * shift;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            format!(
+                r#"<tspan x="{x}" dy="{dy}em">{line}</tspan>"#,
+                x = Num(x),
+                dy = if index == 0 { first_dy } else { line_height },
+                line = Escaped(line),
+            )
+        })
+        .collect()
+}
+
+/// Keeps the labels that can be placed without overlapping, highest priority first.
+pub(crate) fn place_labels(mut labels: Vec<Label>, collision: LabelCollision) -> Vec<Label> {
+    labels.sort_by_key(|label| core::cmp::Reverse(label.style.label_priority));
+    let mut placed: Vec<Label> = Vec::with_capacity(labels.len());
+    for label in labels {
+        let (width, height) = (label.width(), label.height());
+        let offsets: &[(f64, f64)] = match collision {
+            LabelCollision::Drop => &[(0.0, 0.0)],
+            LabelCollision::Nudge => &[
+                (0.0, 0.0),
+                (0.0, -1.0),
+                (0.0, 1.0),
+                (1.0, 0.0),
+                (-1.0, 0.0),
+                (1.0, -1.0),
+                (-1.0, -1.0),
+                (1.0, 1.0),
+                (-1.0, 1.0),
+            ],
+        };
+        let candidate = offsets
+            .iter()
+            .map(|(dx, dy)| Label {
+                x: label.x + dx * width,
+                y: label.y + dy * height,
+                ..label.clone()
+            })
+            .find(|candidate| !placed.iter().any(|other| candidate.overlaps(other)));
+        if let Some(candidate) = candidate {
+            placed.push(candidate);
+        }
+    }
+    placed
+}
+
+/// Pole of inaccessibility of the polygon (the interior point farthest from any edge),
+/// computed with the polylabel algorithm. Unlike the centroid it always lies inside.
+pub(crate) fn representative_point<T: CoordNum>(polygon: &Polygon<T>) -> Option<(f64, f64)> {
+    let rings = core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(ring_to_f64)
+        .collect::<Vec<_>>();
+    let exterior = rings.first().filter(|ring| ring.len() >= 3)?;
+
+    let (min_x, min_y, max_x, max_y) = exterior.iter().fold(
+        (f64::MAX, f64::MAX, f64::MIN, f64::MIN),
+        |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    );
+    let (width, height) = (max_x - min_x, max_y - min_y);
+    let cell_size = width.min(height);
+    if cell_size == 0.0 {
+        return Some((min_x + width / 2.0, min_y + height / 2.0));
+    }
+    let precision = width.max(height) / 1000.0;
+
+    let mut queue = BinaryHeap::new();
+    let half = cell_size / 2.0;
+    let mut x = min_x;
+    while x < max_x {
+        let mut y = min_y;
+        while y < max_y {
+            queue.push(Cell::new(x + half, y + half, half, &rings));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    let mut best = centroid_cell(exterior, &rings);
+    let bbox_center = Cell::new(min_x + width / 2.0, min_y + height / 2.0, 0.0, &rings);
+    if bbox_center.distance > best.distance {
+        best = bbox_center;
+    }
+
+    while let Some(cell) = queue.pop() {
+        if cell.distance > best.distance {
+            best = cell;
+        }
+        if cell.max_distance - best.distance <= precision {
+            continue;
+        }
+        let half = cell.half / 2.0;
+        for (dx, dy) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+            queue.push(Cell::new(
+                cell.x + dx * half,
+                cell.y + dy * half,
+                half,
+                &rings,
+            ));
+        }
+    }
+
+    Some((best.x, best.y))
+}
+
+pub(crate) fn unsigned_area<T: CoordNum>(polygon: &Polygon<T>) -> f64 {
+    let ring_area = |ring: &LineString<T>| {
+        let ring = ring_to_f64(ring);
+        segments(&ring)
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum::<f64>()
+            .abs()
+            / 2.0
+    };
+    ring_area(polygon.exterior()) - polygon.interiors().iter().map(ring_area).sum::<f64>()
+}
+
+fn ring_to_f64<T: CoordNum>(ring: &LineString<T>) -> Vec<(f64, f64)> {
+    ring.coords()
+        .map(|coord| {
+            (
+                NumCast::from(coord.x).unwrap_or(0.0),
+                NumCast::from(coord.y).unwrap_or(0.0),
+            )
+        })
+        .collect()
+}
+
+fn centroid_cell(exterior: &[(f64, f64)], rings: &[Vec<(f64, f64)>]) -> Cell {
+    let (mut area, mut x, mut y) = (0.0, 0.0, 0.0);
+    for (a, b) in segments(exterior) {
+        let cross = a.0 * b.1 - b.0 * a.1;
+        x += (a.0 + b.0) * cross;
+        y += (a.1 + b.1) * cross;
+        area += cross * 3.0;
+    }
+    if area == 0.0 {
+        let (x, y) = exterior[0];
+        Cell::new(x, y, 0.0, rings)
+    } else {
+        Cell::new(x / area, y / area, 0.0, rings)
+    }
+}
+
+/// Signed distance from a point to the polygon outline, positive inside.
+fn signed_distance(x: f64, y: f64, rings: &[Vec<(f64, f64)>]) -> f64 {
+    let mut inside = false;
+    let mut min_distance = f64::INFINITY;
+    for ring in rings {
+        for (a, b) in segments(ring) {
+            if (a.1 > y) != (b.1 > y) && x < (b.0 - a.0) * (y - a.1) / (b.1 - a.1) + a.0 {
+                inside = !inside;
+            }
+            min_distance = min_distance.min(segment_distance(x, y, a, b));
+        }
+    }
+    if inside {
+        min_distance
+    } else {
+        -min_distance
+    }
+}
+
+fn segment_distance(x: f64, y: f64, a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (mut px, mut py) = a;
+    if dx != 0.0 || dy != 0.0 {
+        let t = ((x - a.0) * dx + (y - a.1) * dy) / (dx * dx + dy * dy);
+        if t > 1.0 {
+            px = b.0;
+            py = b.1;
+        } else if t > 0.0 {
+            px += dx * t;
+            py += dy * t;
+        }
+    }
+    (x - px).hypot(y - py)
+}
+
+fn segments(ring: &[(f64, f64)]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+#[derive(Clone, Copy)]
+struct Cell {
+    x: f64,
+    y: f64,
+    half: f64,
+    distance: f64,
+    max_distance: f64,
+}
+
+impl Cell {
+    fn new(x: f64, y: f64, half: f64, rings: &[Vec<(f64, f64)>]) -> Self {
+        let distance = signed_distance(x, y, rings);
+        Self {
+            x,
+            y,
+            half,
+            distance,
+            max_distance: distance + half * core::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance == other.max_distance
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance
+            .partial_cmp(&other.max_distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{place_labels, representative_point, Label, LabelCollision};
+    use crate::Style;
+    use geo_types::{LineString, Polygon};
+
+    #[test]
+    fn test_place_labels() {
+        let style = Style::default();
+        let important = Style {
+            label_priority: 1,
+            ..Style::default()
+        };
+        let labels = vec![
+            Label::new(0.0, 0.0, "low", &style),
+            Label::new(5.0, 0.0, "high", &important),
+            Label::new(500.0, 0.0, "far", &style),
+        ];
+
+        let dropped = place_labels(labels.clone(), LabelCollision::Drop);
+        let texts = dropped.iter().map(|l| l.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, vec!["high", "far"]);
+
+        let nudged = place_labels(labels, LabelCollision::Nudge);
+        assert_eq!(nudged.len(), 3);
+        assert_eq!((nudged[1].x, nudged[1].y), (0.0, -16.0));
+    }
+
+    #[test]
+    fn test_representative_point_concave() {
+        // U shape whose centroid falls in the notch, outside the polygon
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (0.0, 0.0),
+                (30.0, 0.0),
+                (30.0, 30.0),
+                (20.0, 30.0),
+                (20.0, 10.0),
+                (10.0, 10.0),
+                (10.0, 30.0),
+                (0.0, 30.0),
+            ]),
+            vec![],
+        );
+        let (x, y) = representative_point(&polygon).unwrap();
+        assert!(!(10.0..20.0).contains(&x) || y < 10.0, "{} {}", x, y);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::escape::{is_xml_name, Escaped};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Result};
+
+/// Group of an svg and its siblings that viewers and editors can show and hide separately.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    /// Written as the `data-name` attribute of the group.
+    pub name: String,
+    pub visible: bool,
+    /// Extra attributes of the group, e.g. `data-` attributes with the source of the data.
+    /// Their values are escaped, attributes whose name isn't a valid xml name are left out.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Layer {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            visible: true,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Renders the layer with `visibility="hidden"`, to be shown later on.
+    pub fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+
+    pub fn with_attribute(mut self, name: &str, value: &str) -> Self {
+        self.attributes.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Attributes of the group element of the layer.
+impl Display for Layer {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(fmt, r#" data-name="{}""#, Escaped(&self.name))?;
+        if !self.visible {
+            write!(fmt, r#" visibility="hidden""#)?;
+        }
+        for (name, value) in self.attributes.iter().filter(|(name, _)| is_xml_name(name)) {
+            write!(fmt, r#" {}="{}""#, name, Escaped(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Namespaces of the Inkscape attributes, declared on the `<svg>` element.
+pub(crate) const INKSCAPE_NAMESPACES: &str = r#" xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd""#;
+
+/// Attributes of the group of the layer, which Inkscape also shows as a layer.
+pub(crate) struct InkscapeLayer<'a>(pub &'a Layer);
+
+impl Display for InkscapeLayer<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(
+            fmt,
+            r#"{} inkscape:groupmode="layer" inkscape:label="{}""#,
+            self.0,
+            Escaped(&self.0.name)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Layer;
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    #[test]
+    fn test_layer() {
+        let layer = Layer::new("Bus & tram")
+            .hidden()
+            .with_attribute("data-source", "OSM");
+        assert_eq!(
+            layer.to_string(),
+            r#" data-name="Bus &amp; tram" visibility="hidden" data-source="OSM""#
+        );
+
+        let layer = Layer::new("stops").with_attribute(r#"x="1" onload="alert(1)"#, "");
+        assert_eq!(layer.to_string(), r#" data-name="stops""#);
+
+        let stop = Point::new(1.0, 1.0);
+        let svg = stop.to_svg().with_layer(Layer::new("stops")).to_string();
+        assert!(svg.contains(r#"<g data-name="stops"><circle cx="1.0" cy="1.0" r="1"/></g>"#));
+    }
+
+    #[test]
+    fn test_inkscape_layers() {
+        let stop = Point::new(1.0, 1.0);
+        let svg = stop
+            .to_svg()
+            .with_layer(Layer::new("stops").hidden())
+            .with_inkscape_layers()
+            .to_string();
+        assert!(svg.starts_with(r#"<svg xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd" xmlns="http://www.w3.org/2000/svg""#));
+        assert!(svg.contains(r#"<g data-name="stops" visibility="hidden" inkscape:groupmode="layer" inkscape:label="stops">"#));
+    }
+}
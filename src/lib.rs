@@ -9,6 +9,28 @@
 //! - [GeometryCollection](https://docs.rs/geo-types/0.4.3/geo_types/struct.GeometryCollection.html) and all variants of [Geometry](https://docs.rs/geo-types/0.4.3/geo_types/enum.Geometry.html) are supported
 //! - the viewport size is automatically computed to contain all shapes
 //! - style and formatting options are available
+//! - elevations or measures of 3D coordinates can drive the style of their 2D footprint with `ZPoints` and `ZLineString`
+//! - tracks can be colored by a value per vertex, such as speed or elevation, with `SegmentedLineString` and a `ColorRamp`
+//! - geometries can be simplified before rendering with the optional `geo` feature
+//! - coordinates can be projected before rendering, with arbitrary coordinate reference systems through the optional `proj` feature (which needs the PROJ library)
+//! - documents can be rasterized to PNG with the optional `raster` feature
+//! - documents can be exported to vector PDF with the optional `pdf` feature
+//! - documents can be gzip-compressed into `.svgz` with the optional `flate2` feature
+//! - output can be embedded as nodes of the [svg](https://crates.io/crates/svg) crate with the optional `svg` feature
+//! - documents can be parsed into a [usvg](https://crates.io/crates/usvg) tree with the optional `usvg` feature
+//! - styles and colors can be serialized and deserialized with the optional `serde` feature, colors as CSS strings
+//! - layer styles can be loaded from JSON with `StyleSheet::from_json` and the optional `json` feature
+//! - the fill, line and circle layers of Mapbox GL styles can be converted with `StyleSheet::from_mapbox_gl` and the `json` feature
+//! - basic Styled Layer Descriptor rules can be imported with `StyleSheet::from_sld` and the optional `sld` feature
+//! - any [geozero](https://crates.io/crates/geozero) source can be streamed into a document with `SvgProcessor` and the optional `geozero` feature
+//! - the features of a FlatGeobuf file within an extent can be streamed into a document through its spatial index with `render_flatgeobuf` and the optional `flatgeobuf` feature
+//! - WKB and PostGIS EWKB geometries can be read with `from_wkb` and `from_ewkb`, whose SRID can select a projection, with the optional `wkb` feature
+//! - tracks, routes and waypoints of GPX files can be read with `from_gpx` and the optional `gpx` feature
+//! - shapefiles can be read with their attributes, for styling, with `read_shapefile` and the optional `shapefile` feature
+//! - documents are drawn inline in [evcxr](https://github.com/evcxr/evcxr) Jupyter notebooks with the optional `evcxr` feature
+//! - GeoJSON can be rendered from JavaScript in the browser with `render_geojson_to_svg` and the optional `wasm` feature, through [wasm-bindgen](https://crates.io/crates/wasm-bindgen)
+//! - duplicate points, rings without area and self-intersecting rings are reported by `Svg::to_string_with_warnings` with the optional `validation` feature
+//! - the crate is `no_std` with `alloc` when the default `std` feature is disabled, which leaves out `render_iter`, `Svg::save` and the optional integrations
 //!
 //! # Example
 //!
@@ -47,18 +69,128 @@
 //! [`ToSvg`]: svg/trait.ToSvg.html
 //! [`to_svg`]: svg/trait.ToSvg.html#method.to_svg
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod antimeridian;
+mod arrows;
+mod aspect;
+mod axes;
+mod checked;
 mod color;
+mod data_uri;
+mod debug;
+mod decoration;
+mod defs;
+mod elevation;
+mod escape;
+#[cfg(feature = "evcxr")]
+mod evcxr;
+mod finite;
+#[cfg(feature = "flatgeobuf")]
+mod flatgeobuf_reader;
+#[cfg(feature = "geozero")]
+mod geozero_sink;
+#[cfg(feature = "gpx")]
+mod gpx_tracks;
+mod graticule;
+mod great_circle;
+mod html;
+mod inset;
+mod label;
+mod layer;
+#[cfg(feature = "json")]
+mod mapbox;
+mod marker;
+mod measure;
+mod number;
+mod path;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod projection;
+#[cfg(feature = "raster")]
+mod raster;
+#[cfg(feature = "std")]
+mod save;
+mod shape;
+#[cfg(feature = "shapefile")]
+mod shapefile_reader;
+#[cfg(feature = "geo")]
+mod simplify;
+#[cfg(feature = "sld")]
+mod sld;
+mod smooth;
+mod snap;
+#[cfg(feature = "std")]
+mod stream;
 mod style;
+mod style_builder;
+mod styled;
+mod stylesheet;
 mod svg;
 mod svg_impl;
+#[cfg(feature = "svg")]
+mod svg_node;
+#[cfg(feature = "flate2")]
+mod svgz;
+mod symbol;
+mod theme;
+mod tiles;
 mod to_svg;
 mod to_svg_str;
+#[cfg(feature = "usvg")]
+mod usvg_tree;
+#[cfg(feature = "validation")]
+mod validation;
 mod viewbox;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod winding;
+#[cfg(feature = "wkb")]
+mod wkb_reader;
 
+pub use aspect::{AspectAlign, MeetOrSlice};
+pub use axes::Axes;
+pub use checked::{SvgError, TryToSvg};
 pub use color::*;
+pub use decoration::*;
+pub use elevation::{SegmentedLineString, ZLineString, ZPoints};
+pub use finite::{InvalidCoordinates, InvalidGeometryError};
+#[cfg(feature = "flatgeobuf")]
+pub use flatgeobuf_reader::render_flatgeobuf;
+#[cfg(feature = "geozero")]
+pub use geozero_sink::SvgProcessor;
+#[cfg(feature = "gpx")]
+pub use gpx_tracks::from_gpx;
+pub use graticule::Graticule;
+pub use inset::{Facet, Inset};
+pub use label::*;
+pub use layer::Layer;
+#[cfg(feature = "pdf")]
+pub use pdf::PdfError;
+pub use projection::{Project, Projection};
+#[cfg(feature = "raster")]
+pub use raster::RasterError;
+pub use shape::*;
+#[cfg(feature = "shapefile")]
+pub use shapefile_reader::{read_shapefile, ShapeFeature};
+#[cfg(feature = "std")]
+pub use stream::{render_iter, ChunkedWriter, RenderOptions};
 pub use style::*;
-pub use svg::Svg;
-pub use svg_impl::*;
+pub use style_builder::{StyleBuilder, StyleError};
+pub use styled::Styled;
+pub use stylesheet::StyleSheet;
+pub use svg::{Mask, Svg};
+pub use symbol::*;
+pub use theme::Theme;
+pub use tiles::{render_tiles, Tile, TILE_SIZE};
 pub use to_svg::*;
 pub use to_svg_str::*;
+#[cfg(feature = "validation")]
+pub use validation::GeometryWarning;
 pub use viewbox::ViewBox;
+#[cfg(feature = "wasm")]
+pub use wasm::render_geojson_to_svg;
+#[cfg(feature = "wkb")]
+pub use wkb_reader::{from_ewkb, from_wkb, EwkbGeometry};
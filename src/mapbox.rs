@@ -0,0 +1,145 @@
+use crate::{Color, Style, StyleSheet};
+use serde_json::Value;
+
+/// Reads a color with an optional alpha, as `rgba()` and `hsla()` colors have.
+fn color(value: &Value) -> Option<(Color, Option<f32>)> {
+    let value = value.as_str()?.trim();
+    for function in &["rgba", "hsla"] {
+        if let Some(arguments) = value
+            .strip_prefix(function)
+            .and_then(|arguments| arguments.strip_prefix('('))
+            .and_then(|arguments| arguments.strip_suffix(')'))
+        {
+            let (arguments, alpha) = arguments.rsplit_once(',')?;
+            let color = format!("{}({})", &function[..3], arguments).parse().ok()?;
+            return Some((color, Some(alpha.trim().parse().ok()?)));
+        }
+    }
+    Some((value.parse().ok()?, None))
+}
+
+/// Multiplies the optional opacities, `None` standing for fully opaque.
+fn opacity(a: Option<f32>, b: Option<f32>) -> Option<f32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a * b),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Style of a `fill`, `line` or `circle` layer, only constant paint properties are supported.
+fn layer_style(kind: &str, paint: &Value) -> Option<Style> {
+    let number = |property: &str| paint.get(property)?.as_f64().map(|value| value as f32);
+    let color = |property: &str| paint.get(property).and_then(color);
+    let mut style = Style::default();
+    match kind {
+        "fill" => {
+            let fill = color("fill-color").unwrap_or((Color::Named("black"), None));
+            style.fill = Some(fill.0);
+            style.fill_opacity = opacity(number("fill-opacity"), fill.1);
+            if let Some((stroke, alpha)) = color("fill-outline-color") {
+                style.stroke_color = Some(stroke);
+                style.stroke_width = Some(1.0);
+                style.stroke_opacity = opacity(number("fill-opacity"), alpha);
+            }
+        }
+        "line" => {
+            let stroke = color("line-color").unwrap_or((Color::Named("black"), None));
+            style.fill = Some(Color::Named("none"));
+            style.stroke_color = Some(stroke.0);
+            style.stroke_width = Some(number("line-width").unwrap_or(1.0));
+            style.stroke_opacity = opacity(number("line-opacity"), stroke.1);
+        }
+        "circle" => {
+            let fill = color("circle-color").unwrap_or((Color::Named("black"), None));
+            style.radius = number("circle-radius").unwrap_or(5.0);
+            style.fill = Some(fill.0);
+            style.fill_opacity = opacity(number("circle-opacity"), fill.1);
+            if let Some(width) = number("circle-stroke-width") {
+                let stroke = color("circle-stroke-color").unwrap_or((Color::Named("black"), None));
+                style.stroke_color = Some(stroke.0);
+                style.stroke_width = Some(width);
+                style.stroke_opacity = opacity(number("circle-stroke-opacity"), stroke.1);
+            }
+        }
+        _ => return None,
+    }
+    Some(style)
+}
+
+impl StyleSheet {
+    /// Converts the `fill`, `line` and `circle` layers of a Mapbox GL style into styles named
+    /// after the layer ids. Other layer types, hidden layers and paint properties that aren't
+    /// constants, such as expressions or zoom functions, are left out.
+    pub fn from_mapbox_gl(json: &str) -> Result<Self, serde_json::Error> {
+        let document: Value = serde_json::from_str(json)?;
+        let mut sheet = StyleSheet::new();
+        let layers = document.get("layers").and_then(Value::as_array);
+        for layer in layers.into_iter().flatten() {
+            let hidden = layer.pointer("/layout/visibility") == Some(&Value::from("none"));
+            let id = layer.get("id").and_then(Value::as_str);
+            let kind = layer.get("type").and_then(Value::as_str);
+            if let (false, Some(id), Some(kind)) = (hidden, id, kind) {
+                let paint = layer.get("paint").cloned().unwrap_or(Value::Null);
+                if let Some(style) = layer_style(kind, &paint) {
+                    sheet.insert(id, style);
+                }
+            }
+        }
+        Ok(sheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, StyleSheet};
+
+    #[test]
+    fn test_from_mapbox_gl() {
+        let sheet = StyleSheet::from_mapbox_gl(
+            r##"{
+                "version": 8,
+                "layers": [
+                    { "id": "background", "type": "background" },
+                    {
+                        "id": "water",
+                        "type": "fill",
+                        "paint": { "fill-color": "rgba(160, 200, 240, 0.5)", "fill-opacity": 0.8 }
+                    },
+                    {
+                        "id": "roads",
+                        "type": "line",
+                        "paint": {
+                            "line-color": "#f80",
+                            "line-width": ["interpolate", ["linear"], ["zoom"], 5, 1, 10, 4]
+                        }
+                    },
+                    {
+                        "id": "cities",
+                        "type": "circle",
+                        "paint": {
+                            "circle-radius": 3,
+                            "circle-stroke-width": 1,
+                            "circle-stroke-color": "white"
+                        }
+                    },
+                    { "id": "hidden", "type": "line", "layout": { "visibility": "none" } }
+                ]
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(
+            sheet.layers().collect::<Vec<_>>(),
+            vec!["cities", "roads", "water"]
+        );
+        let water = sheet.get("water").unwrap();
+        assert_eq!(water.fill, Some(Color::Rgb(160, 200, 240)));
+        assert_eq!(water.fill_opacity, Some(0.4));
+        let roads = sheet.get("roads").unwrap();
+        assert_eq!(roads.stroke_color, Some(Color::Hex(0xFF8800)));
+        assert_eq!(roads.stroke_width, Some(1.0));
+        assert_eq!(roads.fill, Some(Color::Named("none")));
+        let cities = sheet.get("cities").unwrap();
+        assert_eq!(cities.radius, 3.0);
+        assert_eq!(cities.stroke_color, Some(Color::Named("white")));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::number::Num;
+use crate::{Color, PointType, Style};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::f64::consts::{FRAC_PI_2, PI};
+use core::fmt::Write;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// Reference shapes of the built-in point symbols, as vertices of a closed outline fitting
+/// the unit circle (the square fits its bounding box). They are scaled by the style radius.
+fn unit_outline(point_type: &PointType) -> Option<Vec<(f64, f64)>> {
+    Some(match point_type {
+        PointType::Square => vec![(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)],
+        PointType::Triangle => regular_polygon(3, 1.0, 0.0),
+        PointType::Diamond => regular_polygon(4, 1.0, 0.0),
+        PointType::Star => {
+            let outer = regular_polygon(5, 1.0, 0.0);
+            let inner = regular_polygon(5, 0.382, PI / 5.0);
+            outer
+                .into_iter()
+                .zip(inner)
+                .flat_map(|(o, i)| [o, i])
+                .collect()
+        }
+        PointType::Cross => cross(0.0),
+        PointType::X => cross(PI / 4.0),
+        _ => return None,
+    })
+}
+
+/// Vertices of a regular polygon pointing upwards (towards negative y) once rotated by `angle`.
+fn regular_polygon(sides: usize, radius: f64, angle: f64) -> Vec<(f64, f64)> {
+    (0..sides)
+        .map(|i| {
+            let theta = angle + 2.0 * PI * i as f64 / sides as f64 - FRAC_PI_2;
+            (radius * theta.cos(), radius * theta.sin())
+        })
+        .collect()
+}
+
+fn cross(angle: f64) -> Vec<(f64, f64)> {
+    const ARM: f64 = 0.3;
+    let quarter = [(ARM, -1.0), (ARM, -ARM), (1.0, -ARM)];
+    (0..4)
+        .flat_map(|i| {
+            let theta = angle + FRAC_PI_2 * i as f64;
+            let (sin, cos) = theta.sin_cos();
+            quarter
+                .iter()
+                .map(move |(x, y)| (x * cos - y * sin, x * sin + y * cos))
+        })
+        .collect()
+}
+
+/// Path data of a built-in symbol centered on `(x, y)`, written with relative commands to
+/// keep it short. Returns `None` for point types that aren't drawn as a path.
+pub(crate) fn symbol_path(point_type: &PointType, x: f64, y: f64, radius: f64) -> Option<String> {
+    let mut path = String::new();
+    let mut previous = (0.0, 0.0);
+    for (index, (dx, dy)) in unit_outline(point_type)?.into_iter().enumerate() {
+        let (px, py) = (round(dx * radius), round(dy * radius));
+        if index == 0 {
+            write!(path, "M{} {}", round(x + px), round(y + py)).unwrap();
+        } else {
+            write!(
+                path,
+                "l{} {}",
+                round(px - previous.0),
+                round(py - previous.1)
+            )
+            .unwrap();
+        }
+        previous = (px, py);
+    }
+    path.push('z');
+    Some(path)
+}
+
+/// Teardrop map pin whose tip is on `(x, y)`, `2 * radius` tall, with a hole in its head.
+pub(crate) fn pin_path(x: f64, y: f64, radius: f64) -> String {
+    // head of radius 0.75r centered 1.25r above the tip, the sides are tangent to it
+    let (head_radius, head_y) = (0.75 * radius, y - 1.25 * radius);
+    let hole_radius = 0.3 * radius;
+    format!(
+        "M{x} {y}L{left} {tangent_y}A{hr} {hr} 0 1 1 {right} {tangent_y}z\
+         M{hole_left} {head_y}a{r} {r} 0 1 0 {d} 0a{r} {r} 0 1 0 -{d} 0z",
+        x = round(x),
+        y = round(y),
+        left = round(x - 0.6 * radius),
+        right = round(x + 0.6 * radius),
+        tangent_y = round(y - 0.8 * radius),
+        hr = round(head_radius),
+        hole_left = round(x - hole_radius),
+        head_y = round(head_y),
+        r = round(hole_radius),
+        d = round(2.0 * hole_radius),
+    )
+}
+
+/// Marker whose size is in screen pixels whatever the zoom level: a zero-length line with a
+/// `cap` ended, non-scaling stroke, painted with the fill and outlined with the stroke.
+pub(crate) fn pixel_marker(x: f64, y: f64, cap: &str, style: &Style) -> String {
+    let dot = |color: Color, width: f32, opacity: Option<f32>| {
+        format!(
+            r#"<path d="M{x} {y}h0" stroke="{color}" stroke-width="{width}"{opacity} stroke-linecap="{cap}" vector-effect="non-scaling-stroke"/>"#,
+            x = Num(x),
+            y = Num(y),
+            color = color,
+            width = width,
+            opacity = opacity
+                .map(|opacity| format!(r#" stroke-opacity="{}""#, opacity))
+                .unwrap_or_default(),
+            cap = cap,
+        )
+    };
+    let diameter = style.radius * 2.0;
+    let outline = match (style.stroke_color, style.stroke_width) {
+        (Some(color), Some(width)) => dot(color, diameter + width * 2.0, style.stroke_opacity),
+        _ => String::new(),
+    };
+    let fill = dot(
+        style.fill.unwrap_or(Color::Named("black")),
+        diameter,
+        style.fill_opacity,
+    );
+    let group_style = Style {
+        fill: None,
+        fill_opacity: None,
+        stroke_color: None,
+        stroke_width: None,
+        stroke_opacity: None,
+        ..style.clone()
+    };
+    format!("<g{}>{}{}</g>", group_style, outline, fill)
+}
+
+/// Rounds to a precision that's invisible at any sensible marker size but avoids
+/// float noise such as `0.30000000000000004` in the output.
+pub(crate) fn round(value: f64) -> f64 {
+    let rounded = (value * 1e6).round() / 1e6;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pin_path, symbol_path};
+    use crate::PointType;
+
+    #[test]
+    fn test_symbol_paths() {
+        assert_eq!(
+            symbol_path(&PointType::Square, 10.0, 20.0, 2.0).unwrap(),
+            "M8 18l4 0l0 4l-4 0z"
+        );
+        assert_eq!(
+            symbol_path(&PointType::Diamond, 0.0, 0.0, 1.0).unwrap(),
+            "M0 -1l1 1l-1 1l-1 -1z"
+        );
+        assert_eq!(symbol_path(&PointType::Circle, 0.0, 0.0, 1.0), None);
+    }
+
+    #[test]
+    fn test_pin_path() {
+        assert_eq!(
+            pin_path(0.0, 0.0, 10.0),
+            "M0 0L-6 -8A7.5 7.5 0 1 1 6 -8zM-3 -12.5a3 3 0 1 0 6 0a3 3 0 1 0 -6 0z"
+        );
+    }
+}
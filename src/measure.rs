@@ -0,0 +1,171 @@
+use crate::escape::Escaped;
+use crate::marker::round;
+use crate::number::Num;
+use crate::Style;
+use alloc::{format, string::String};
+use core::fmt::Write;
+#[cfg(feature = "geo")]
+use geo::{GeodesicArea, MapCoords};
+#[cfg(feature = "geo")]
+use geo_types::{Coord, Polygon};
+use geo_types::{CoordNum, Line};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+/// Length of every segment written above its middle, along the segment and never upside
+/// down, with the style's font size.
+pub(crate) fn segment_lengths<T: CoordNum>(
+    lines: impl IntoIterator<Item = Line<T>>,
+    style: &Style,
+) -> String {
+    let (unit, precision) = match &style.segment_lengths {
+        Some((unit, precision)) => (unit, *precision),
+        None => return String::new(),
+    };
+    let mut labels = String::new();
+    for line in lines {
+        let coords: Option<(f64, f64, f64, f64)> = (|| {
+            Some((
+                NumCast::from(line.start.x)?,
+                NumCast::from(line.start.y)?,
+                NumCast::from(line.end.x)?,
+                NumCast::from(line.end.y)?,
+            ))
+        })();
+        let (x0, y0, x1, y1) = match coords {
+            Some(coords) => coords,
+            None => continue,
+        };
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        let mut angle = dy.atan2(dx).to_degrees();
+        if angle > 90.0 {
+            angle -= 180.0;
+        } else if angle < -90.0 {
+            angle += 180.0;
+        }
+        let (x, y) = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+        write!(
+            labels,
+            r#"<text x="{x}" y="{y}" dy="-0.3em" transform="rotate({angle} {x} {y})">{length:.precision$}{separator}{unit}</text>"#,
+            x = Num(x),
+            y = Num(y),
+            angle = round(angle),
+            length = dx.hypot(dy),
+            precision = precision,
+            separator = if unit.is_empty() { "" } else { " " },
+            unit = Escaped(unit),
+        )
+        .unwrap();
+    }
+    if labels.is_empty() {
+        return labels;
+    }
+    format!(
+        r#"<g class="segment-lengths" text-anchor="middle"{font_size}>{labels}</g>"#,
+        font_size = style
+            .font_size
+            .map(|size| format!(r#" font-size="{}""#, size))
+            .unwrap_or_default(),
+        labels = labels,
+    )
+}
+
+/// Writes `area` in place of the `{area}` placeholders of `format`, or with `N` decimals in
+/// place of `{area:.N}`.
+pub(crate) fn area_text(format: &str, area: f64) -> String {
+    let mut text = String::new();
+    let mut rest = format;
+    while let Some(start) = rest.find("{area") {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        let spec = &rest[start + "{area".len()..end];
+        let precision = match spec.strip_prefix(":.").map(str::parse::<usize>) {
+            Some(Ok(precision)) => Some(precision),
+            _ if spec.is_empty() => None,
+            // not a placeholder, e.g. `{areas}`
+            _ => {
+                text.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+        text.push_str(&rest[..start]);
+        match precision {
+            Some(precision) => write!(text, "{:.*}", precision, area),
+            None => write!(text, "{}", area),
+        }
+        .unwrap();
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Area of a polygon with lon/lat coordinates in square meters, on the WGS84 ellipsoid.
+#[cfg(feature = "geo")]
+pub(crate) fn geodesic_area<T: CoordNum>(polygon: &Polygon<T>) -> f64 {
+    polygon
+        .map_coords(|coord| Coord {
+            x: NumCast::from(coord.x).unwrap_or(0.0),
+            y: NumCast::from(coord.y).unwrap_or(0.0),
+        })
+        .geodesic_area_unsigned()
+}
+
+/// Style labelling the polygon with its area, under its label if it has one.
+pub(crate) fn area_label_style(style: &Style, area: f64) -> Style {
+    let area = area_text(style.area_label.as_deref().unwrap_or("{area}"), area);
+    Style {
+        label: Some(match &style.label {
+            Some(label) => format!("{}\n{}", label, area),
+            None => area,
+        }),
+        area_label: None,
+        geodesic_area: false,
+        ..style.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{area_text, segment_lengths};
+    use crate::Style;
+    use geo_types::LineString;
+
+    #[test]
+    fn test_segment_lengths() {
+        let style = Style {
+            segment_lengths: Some(("m".into(), 1)),
+            ..Style::default()
+        };
+        let line_string = LineString::from(vec![(0.0, 0.0), (3.0, 4.0), (0.0, 4.0)]);
+        assert_eq!(
+            segment_lengths(line_string.lines(), &style),
+            r#"<g class="segment-lengths" text-anchor="middle"><text x="1.5" y="2.0" dy="-0.3em" transform="rotate(53.130102 1.5 2.0)">5.0 m</text><text x="1.5" y="4.0" dy="-0.3em" transform="rotate(0 1.5 4.0)">3.0 m</text></g>"#
+        );
+        assert_eq!(segment_lengths(line_string.lines(), &Style::default()), "");
+    }
+
+    #[test]
+    fn test_area_text() {
+        assert_eq!(area_text("{area} m²", 12.0), "12 m²");
+        assert_eq!(area_text("{area:.2} ha", 0.125), "0.12 ha");
+        assert_eq!(area_text("{areas} {area:.1}", 2.25), "{areas} 2.2");
+        assert_eq!(area_text("{area", 1.0), "{area");
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn test_geodesic_area() {
+        use super::geodesic_area;
+        use geo_types::{LineString, Polygon};
+
+        // one degree square at the equator
+        let square = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let area = geodesic_area(&Polygon::new(square, vec![]));
+        assert!((area - 12_308_778_361.0).abs() < 1e6, "{}", area);
+    }
+}
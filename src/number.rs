@@ -0,0 +1,85 @@
+use alloc::format;
+use core::fmt::{Display, Formatter, Result};
+use geo_types::CoordNum;
+use num_traits::NumCast;
+
+/// A coordinate as written in svg attributes: integers as they are, floats in their shortest
+/// form that reads back to the same value, with a decimal point and never an exponent, e.g.
+/// `10`, `10.0` or `0.0000001`.
+#[derive(Clone, Copy)]
+pub(crate) struct Num<T>(pub T);
+
+impl<T: CoordNum> Display for Num<T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let value = self.0;
+        // integer types truncate a half to zero
+        let is_integer = T::from(0.5).is_none_or(|half| half == T::zero());
+        if is_integer {
+            if let Some(integer) = value.to_i128() {
+                return fmt.write_str(itoa::Buffer::new().format(integer));
+            }
+        }
+        let float: f64 = NumCast::from(value).unwrap_or(f64::NAN);
+        let mut buffer = ryu::Buffer::new();
+        let shortest = if core::mem::size_of::<T>() == 4 {
+            buffer.format(float as f32)
+        } else {
+            buffer.format(float)
+        };
+        if shortest.contains('e') {
+            // the plain notation is slower to get but has no exponent
+            let plain = if core::mem::size_of::<T>() == 4 {
+                format!("{}", float as f32)
+            } else {
+                format!("{}", float)
+            };
+            fmt.write_str(&plain)?;
+            if !plain.contains('.') {
+                fmt.write_str(".0")?;
+            }
+            Ok(())
+        } else {
+            fmt.write_str(shortest)
+        }
+    }
+}
+
+/// A bound of the viewbox as written in the `viewBox` attribute: rounded to 12 significant
+/// digits, which hides the noise of float arithmetic, e.g. `-18.26` rather than
+/// `-18.259999999999998`, and without a decimal point for integers.
+#[derive(Clone, Copy)]
+pub(crate) struct Bound(pub f64);
+
+impl Display for Bound {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let rounded = format!("{:.11e}", self.0).parse().unwrap_or(self.0);
+        write!(fmt, "{}", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bound, Num};
+
+    #[test]
+    fn test_num() {
+        assert_eq!(Num(10).to_string(), "10");
+        assert_eq!(Num(-3i64).to_string(), "-3");
+        assert_eq!(Num(10.0).to_string(), "10.0");
+        assert_eq!(Num(114.19).to_string(), "114.19");
+        assert_eq!(Num(0.1f32).to_string(), "0.1");
+        assert_eq!(Num(1e-7).to_string(), "0.0000001");
+        assert_eq!(Num(2e21).to_string(), "2000000000000000000000.0");
+        assert_eq!(Num(-3e20f32).to_string(), "-300000000000000000000.0");
+        assert_eq!(Num(f64::NAN).to_string(), "NaN");
+    }
+
+    #[test]
+    fn test_bound() {
+        assert_eq!(Bound(-3.0).to_string(), "-3");
+        assert_eq!(Bound(-18.259999999999998).to_string(), "-18.26");
+        assert_eq!(Bound(0.1 + 0.2).to_string(), "0.3");
+        assert_eq!(Bound(500_000.123_456).to_string(), "500000.123456");
+        assert_eq!(Bound(f64::NAN).to_string(), "NaN");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::number::Num;
+use crate::PathEncoding;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt::Write;
+use geo_types::{Coord, CoordNum};
+
+/// Writes path data from absolute coordinates, in the requested encoding.
+pub(crate) struct PathBuilder<T: CoordNum> {
+    data: String,
+    encoding: PathEncoding,
+    subpath_start: Option<Coord<T>>,
+    current: Option<Coord<T>>,
+}
+
+impl<T: CoordNum> PathBuilder<T> {
+    pub(crate) fn new(encoding: PathEncoding) -> Self {
+        Self {
+            data: String::new(),
+            encoding,
+            subpath_start: None,
+            current: None,
+        }
+    }
+
+    pub(crate) fn move_to(&mut self, coord: Coord<T>) {
+        self.command('M', coord);
+        self.subpath_start = Some(coord);
+    }
+
+    pub(crate) fn line_to(&mut self, coord: Coord<T>) {
+        self.command('L', coord);
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.separate();
+        self.data.push(match self.encoding {
+            PathEncoding::Absolute => 'Z',
+            PathEncoding::Relative => 'z',
+        });
+        self.current = self.subpath_start;
+    }
+
+    pub(crate) fn finish(self) -> String {
+        self.data
+    }
+
+    fn command(&mut self, command: char, coord: Coord<T>) {
+        self.separate();
+        match (self.encoding, self.current) {
+            (PathEncoding::Relative, Some(current)) => write!(
+                self.data,
+                "{} {} {}",
+                command.to_ascii_lowercase(),
+                delta(current.x, coord.x),
+                delta(current.y, coord.y)
+            ),
+            _ => write!(self.data, "{} {} {}", command, Num(coord.x), Num(coord.y)),
+        }
+        .unwrap();
+        self.current = Some(coord);
+    }
+
+    fn separate(&mut self) {
+        if !self.data.is_empty() {
+            self.data.push(' ');
+        }
+    }
+}
+
+/// Formats `to - from`, without underflowing unsigned coordinates.
+fn delta<T: CoordNum>(from: T, to: T) -> String {
+    if to >= from {
+        Num(to - from).to_string()
+    } else {
+        format!("-{}", Num(from - to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathBuilder;
+    use crate::PathEncoding;
+    use geo_types::Coord;
+
+    fn square(encoding: PathEncoding) -> String {
+        let mut path = PathBuilder::<u32>::new(encoding);
+        path.move_to(Coord { x: 10, y: 10 });
+        path.line_to(Coord { x: 20, y: 10 });
+        path.line_to(Coord { x: 20, y: 20 });
+        path.close();
+        path.move_to(Coord { x: 12, y: 12 });
+        path.line_to(Coord { x: 5, y: 15 });
+        path.finish()
+    }
+
+    #[test]
+    fn test_path_encodings() {
+        assert_eq!(
+            square(PathEncoding::Absolute),
+            "M 10 10 L 20 10 L 20 20 Z M 12 12 L 5 15"
+        );
+        assert_eq!(
+            square(PathEncoding::Relative),
+            "M 10 10 l 10 0 l 0 10 z m 2 2 l -7 3"
+        );
+    }
+}
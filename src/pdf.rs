@@ -0,0 +1,60 @@
+use crate::usvg_tree::parse_tree;
+use crate::Svg;
+use std::fmt::{Display, Formatter, Result};
+use svg2pdf::{ConversionOptions, PageOptions};
+
+#[derive(Debug)]
+pub enum PdfError {
+    /// The rendered document couldn't be parsed, e.g. because of invalid custom css or paths.
+    Parse(usvg::Error),
+    Convert(String),
+}
+
+impl Display for PdfError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            PdfError::Parse(error) => write!(fmt, "invalid svg: {}", error),
+            PdfError::Convert(error) => write!(fmt, "pdf conversion failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for PdfError {}
+
+impl Svg<'_> {
+    /// Converts the document into a single page vector PDF of `width` by `height` points, the
+    /// drawing is scaled to fit and centered. Text is drawn with the system fonts.
+    pub fn to_pdf(&self, width: f32, height: f32) -> std::result::Result<Vec<u8>, PdfError> {
+        let document = self.document(Some((width.into(), height.into())), "");
+        let tree = parse_tree(&document).map_err(PdfError::Parse)?;
+        svg2pdf::to_pdf(&tree, ConversionOptions::default(), PageOptions::default())
+            .map_err(|error| PdfError::Convert(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, ToSvg};
+    use geo_types::Rect;
+
+    #[test]
+    fn test_to_pdf() {
+        let rect = Rect::new((0.0, 0.0), (1000.0, 1000.0));
+        let pdf = rect
+            .to_svg()
+            .with_fill_color(Color::Named("red"))
+            .to_pdf(595.0, 842.0)
+            .unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with("%PDF-"));
+        assert!(pdf.contains("/MediaBox [0 0 595 842]"));
+
+        // the page size replaces the canvas size
+        let pdf = rect
+            .to_svg()
+            .with_canvas(200.0, 100.0, 5.0)
+            .to_pdf(100.0, 100.0)
+            .unwrap();
+        assert!(String::from_utf8_lossy(&pdf).contains("/MediaBox [0 0 100 100]"));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{Style, ToSvgStr};
+use alloc::sync::Arc;
+use core::f64::consts::PI;
+use core::fmt::{Debug, Formatter, Result};
+use geo_types::{Coord, CoordNum, Line, LineString, Point, Polygon, Rect};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+/// Transformation of coordinates applied before rendering.
+pub trait Project {
+    fn project(&self, coord: Coord<f64>) -> Coord<f64>;
+}
+
+impl<F: Fn(Coord<f64>) -> Coord<f64>> Project for F {
+    fn project(&self, coord: Coord<f64>) -> Coord<f64> {
+        self(coord)
+    }
+}
+
+/// Projection of longitude/latitude coordinates in degrees to the svg user space, whose y
+/// axis points down so that north is up.
+#[derive(Clone)]
+pub enum Projection {
+    /// Spherical Mercator (EPSG:3857) in meters, latitudes are clamped to ±85.05°.
+    WebMercator,
+    /// Any transformation, from and to whatever coordinates it's designed for. It's shared
+    /// between threads with the styles it's part of.
+    Custom(Arc<dyn Project + Send + Sync>),
+}
+
+const EARTH_RADIUS: f64 = 6_378_137.0;
+const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+impl Projection {
+    pub fn custom<P: Project + Send + Sync + 'static>(projection: P) -> Self {
+        Projection::Custom(Arc::new(projection))
+    }
+
+    /// Transformation between two coordinate reference systems known to PROJ, such as
+    /// `"EPSG:4326"` and `"EPSG:32631"`, with its y axis flipped so that north is up.
+    #[cfg(feature = "proj")]
+    pub fn from_crs(from: &str, to: &str) -> core::result::Result<Self, proj::ProjCreateError> {
+        let transform = proj::Proj::new_known_crs(from, to, None)?;
+        Ok(Projection::custom(ProjTransform(std::sync::Mutex::new(
+            SendProj(transform),
+        ))))
+    }
+
+    /// Projection rendering coordinates of the EPSG code `srid`, e.g. from EWKB, north up:
+    /// longitudes and latitudes (4326) with [`Projection::WebMercator`], Web Mercator
+    /// coordinates (3857) as they are, and other systems through PROJ with the `proj` feature.
+    pub fn from_srid(srid: i32) -> Option<Self> {
+        match srid {
+            4326 => Some(Projection::WebMercator),
+            3857 => Some(Projection::custom(|coord: Coord<f64>| Coord {
+                x: coord.x,
+                y: 0.0 - coord.y,
+            })),
+            #[cfg(feature = "proj")]
+            srid => Projection::from_crs(&alloc::format!("EPSG:{}", srid), "EPSG:3857").ok(),
+            #[cfg(not(feature = "proj"))]
+            _ => None,
+        }
+    }
+}
+
+impl Project for Projection {
+    fn project(&self, coord: Coord<f64>) -> Coord<f64> {
+        match self {
+            Projection::WebMercator => {
+                let latitude = coord
+                    .y
+                    .clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE)
+                    .to_radians();
+                Coord {
+                    x: EARTH_RADIUS * coord.x * PI / 180.0,
+                    y: 0.0 - EARTH_RADIUS * latitude.tan().asinh(),
+                }
+            }
+            Projection::Custom(projection) => projection.project(coord),
+        }
+    }
+}
+
+impl Debug for Projection {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            Projection::WebMercator => write!(fmt, "WebMercator"),
+            Projection::Custom(_) => write!(fmt, "Custom"),
+        }
+    }
+}
+
+/// Custom projections are only equal to themselves.
+impl PartialEq for Projection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Projection::WebMercator, Projection::WebMercator) => true,
+            (Projection::Custom(a), Projection::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// PROJ object with its own context, which may be moved to another thread but not used by
+/// two at once.
+#[cfg(feature = "proj")]
+struct SendProj(proj::Proj);
+
+// SAFETY: every `Proj` is created with a context of its own, which PROJ allows to be used
+// from any thread as long as calls aren't concurrent, which the mutex around it ensures.
+#[cfg(feature = "proj")]
+unsafe impl Send for SendProj {}
+
+/// Coordinates which PROJ fails to transform become NaN.
+#[cfg(feature = "proj")]
+struct ProjTransform(std::sync::Mutex<SendProj>);
+
+#[cfg(feature = "proj")]
+impl Project for ProjTransform {
+    fn project(&self, coord: Coord<f64>) -> Coord<f64> {
+        let proj = match self.0.lock() {
+            Ok(proj) => proj,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        match proj.0.convert((coord.x, coord.y)) {
+            Ok((x, y)) => Coord { x, y: 0.0 - y },
+            Err(_) => Coord {
+                x: f64::NAN,
+                y: f64::NAN,
+            },
+        }
+    }
+}
+
+/// Geometries whose coordinates are projected before rendering.
+pub(crate) trait Projectable {
+    type Projected: ToSvgStr;
+
+    fn project(&self, projection: &dyn Project) -> Self::Projected;
+}
+
+/// The projected geometry and the style to render it with, if the style has a projection.
+pub(crate) fn projected<G: Projectable>(
+    geometry: &G,
+    style: &Style,
+) -> Option<(G::Projected, Style)> {
+    style.projection.as_ref().map(|projection| {
+        (
+            geometry.project(projection),
+            Style {
+                projection: None,
+                ..style.clone()
+            },
+        )
+    })
+}
+
+/// The geometry with its x and y exchanged and the style to render it with, if the style
+/// swaps axes. It comes before any other transformation, which then gets x first.
+pub(crate) fn swapped<G: Projectable>(
+    geometry: &G,
+    style: &Style,
+) -> Option<(G::Projected, Style)> {
+    if !style.swap_axes {
+        return None;
+    }
+    let swap = |coord: Coord<f64>| Coord {
+        x: coord.y,
+        y: coord.x,
+    };
+    Some((
+        geometry.project(&swap),
+        Style {
+            swap_axes: false,
+            ..style.clone()
+        },
+    ))
+}
+
+fn project_coord<T: CoordNum>(coord: Coord<T>, projection: &dyn Project) -> Coord<f64> {
+    projection.project(Coord {
+        x: NumCast::from(coord.x).unwrap_or(0.0),
+        y: NumCast::from(coord.y).unwrap_or(0.0),
+    })
+}
+
+impl<T: CoordNum> Projectable for Point<T> {
+    type Projected = Point<f64>;
+
+    fn project(&self, projection: &dyn Project) -> Point<f64> {
+        Point(project_coord(self.0, projection))
+    }
+}
+
+impl<T: CoordNum> Projectable for Line<T> {
+    type Projected = Line<f64>;
+
+    fn project(&self, projection: &dyn Project) -> Line<f64> {
+        Line::new(
+            project_coord(self.start, projection),
+            project_coord(self.end, projection),
+        )
+    }
+}
+
+impl<T: CoordNum> Projectable for LineString<T> {
+    type Projected = LineString<f64>;
+
+    fn project(&self, projection: &dyn Project) -> LineString<f64> {
+        self.coords()
+            .map(|coord| project_coord(*coord, projection))
+            .collect()
+    }
+}
+
+impl<T: CoordNum> Projectable for Polygon<T> {
+    type Projected = Polygon<f64>;
+
+    fn project(&self, projection: &dyn Project) -> Polygon<f64> {
+        Polygon::new(
+            self.exterior().project(projection),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.project(projection))
+                .collect(),
+        )
+    }
+}
+
+/// Rectangles are projected as polygons since most projections, such as rotations, don't keep
+/// them aligned with the axes.
+impl<T: CoordNum> Projectable for Rect<T> {
+    type Projected = Polygon<f64>;
+
+    fn project(&self, projection: &dyn Project) -> Polygon<f64> {
+        Polygon::from(*self).project(projection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Project, Projection};
+    use geo_types::Coord;
+
+    #[test]
+    fn test_web_mercator() {
+        let project = |x, y| Projection::WebMercator.project(Coord { x, y });
+        assert_eq!(project(0.0, 0.0), Coord { x: 0.0, y: 0.0 });
+        let paris = project(2.3522, 48.8566);
+        assert!((paris.x - 261_845.7).abs() < 1.0);
+        assert!((paris.y + 6_250_564.3).abs() < 1.0);
+        assert!(project(0.0, 90.0).y.is_finite());
+    }
+
+    #[test]
+    fn test_custom_projection() {
+        let swap = Projection::custom(|coord: Coord<f64>| Coord {
+            x: coord.y,
+            y: coord.x,
+        });
+        assert_eq!(
+            swap.project(Coord { x: 1.0, y: 2.0 }),
+            Coord { x: 2.0, y: 1.0 }
+        );
+        assert_eq!(swap, swap.clone());
+        assert_ne!(swap, Projection::WebMercator);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Projection>();
+        assert_send_sync::<crate::Style>();
+        assert_send_sync::<crate::StyleSheet>();
+    }
+
+    #[test]
+    fn test_from_srid() {
+        assert_eq!(Projection::from_srid(4326), Some(Projection::WebMercator));
+        let web_mercator = Projection::from_srid(3857).unwrap();
+        assert_eq!(
+            web_mercator.project(Coord { x: 1.0, y: 2.0 }),
+            Coord { x: 1.0, y: -2.0 }
+        );
+        assert_eq!(Projection::from_srid(0), None);
+    }
+}
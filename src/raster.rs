@@ -0,0 +1,63 @@
+use crate::usvg_tree::parse_tree;
+use crate::Svg;
+use resvg::tiny_skia;
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug)]
+pub enum RasterError {
+    /// The rendered document couldn't be parsed, e.g. because of invalid custom css or paths.
+    Parse(usvg::Error),
+    /// The image is empty or too large.
+    Size,
+    Encode(String),
+}
+
+impl Display for RasterError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            RasterError::Parse(error) => write!(fmt, "invalid svg: {}", error),
+            RasterError::Size => write!(fmt, "invalid image size"),
+            RasterError::Encode(error) => write!(fmt, "png encoding failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for RasterError {}
+
+impl Svg<'_> {
+    /// Rasterizes the document into a `width` by `height` PNG image, the drawing is scaled to
+    /// fit and centered like in a browser. Text is drawn with the system fonts.
+    pub fn to_png(&self, width: u32, height: u32) -> std::result::Result<Vec<u8>, RasterError> {
+        let tree = parse_tree(&self.to_string()).map_err(RasterError::Parse)?;
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or(RasterError::Size)?;
+        let size = tree.size();
+        let scale = (width as f32 / size.width()).min(height as f32 / size.height());
+        let transform = tiny_skia::Transform::from_translate(
+            (width as f32 - size.width() * scale) / 2.0,
+            (height as f32 - size.height() * scale) / 2.0,
+        )
+        .pre_scale(scale, scale);
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+        pixmap
+            .encode_png()
+            .map_err(|error| RasterError::Encode(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, ToSvg};
+    use geo_types::Rect;
+
+    #[test]
+    fn test_to_png() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let png = rect
+            .to_svg()
+            .with_fill_color(Color::Named("red"))
+            .to_png(32, 16)
+            .unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+        assert!(rect.to_svg().to_png(0, 0).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::Svg;
+use std::fs::{self, OpenOptions};
+use std::io::{Error, ErrorKind, Result, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of the next temporary file of the process, so that concurrent saves to the same
+/// path don't write the same temporary file.
+static NEXT_TEMPORARY: AtomicUsize = AtomicUsize::new(0);
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    // fails rather than sharing a temporary file left by another process
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+impl Svg<'_> {
+    /// Writes the document to the file at `path`, replacing it. The document is written to a
+    /// temporary file next to it first, which is then renamed, so that the file is never left
+    /// half written if the process is killed.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut temporary_name = path
+            .file_name()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "path has no file name"))?
+            .to_os_string();
+        temporary_name.push(format!(
+            ".{}-{}.tmp",
+            std::process::id(),
+            NEXT_TEMPORARY.fetch_add(1, Ordering::Relaxed)
+        ));
+        let temporary_path = path.with_file_name(temporary_name);
+        if let Err(error) = write_file(&temporary_path, self.to_string().as_bytes()) {
+            let _ = fs::remove_file(&temporary_path);
+            return Err(error);
+        }
+        fs::rename(&temporary_path, path).inspect_err(|_| {
+            let _ = fs::remove_file(&temporary_path);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSvg;
+    use geo_types::Point;
+    use std::fs;
+
+    #[test]
+    fn test_save() {
+        let directory = std::env::temp_dir().join(format!("geo-svg-save-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("point.svg");
+        fs::write(&path, "previous").unwrap();
+
+        let point = Point::new(1.0, 2.0);
+        point.to_svg().save(&path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            point.to_svg().to_string()
+        );
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+
+        assert!(point
+            .to_svg()
+            .save(directory.join("missing/point.svg"))
+            .is_err());
+        let error = point.to_svg().save(directory.join("..")).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_saves() {
+        let directory = std::env::temp_dir().join(format!("geo-svg-saves-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("points.svg");
+
+        let points: Vec<_> = (0..8).map(|x| Point::new(x as f64, 0.0)).collect();
+        std::thread::scope(|scope| {
+            for point in &points {
+                let path = &path;
+                scope.spawn(move || point.to_svg().save(path).unwrap());
+            }
+        });
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(points
+            .iter()
+            .any(|point| point.to_svg().to_string() == saved));
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
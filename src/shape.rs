@@ -0,0 +1,145 @@
+use crate::number::Num;
+use crate::{Label, Style, ToSvgStr, ViewBox};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use geo_types::{Coord, CoordNum};
+use num_traits::NumCast;
+
+/// A circle rendered as a native `<circle>` element rather than an approximating polygon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T: CoordNum> {
+    pub center: Coord<T>,
+    pub radius: T,
+}
+
+/// An axis-aligned ellipse rendered as a native `<ellipse>` element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse<T: CoordNum> {
+    pub center: Coord<T>,
+    pub radius_x: T,
+    pub radius_y: T,
+}
+
+impl<T: CoordNum> Circle<T> {
+    pub fn new(center: Coord<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: CoordNum> Ellipse<T> {
+    pub fn new(center: Coord<T>, radius_x: T, radius_y: T) -> Self {
+        Self {
+            center,
+            radius_x,
+            radius_y,
+        }
+    }
+}
+
+impl<T: CoordNum> ToSvgStr for Circle<T> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        format!(
+            r#"<circle cx="{x}" cy="{y}" r="{radius}"{style}/>{labels}"#,
+            x = Num(self.center.x),
+            y = Num(self.center.y),
+            radius = Num(self.radius),
+            style = style,
+            labels = center_labels(self.center, style),
+        )
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        Ellipse::new(self.center, self.radius, self.radius).viewbox(style)
+    }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        Ellipse::new(self.center, self.radius, self.radius).labels(style)
+    }
+
+    fn is_finite(&self) -> bool {
+        Ellipse::new(self.center, self.radius, self.radius).is_finite()
+    }
+}
+
+impl<T: CoordNum> ToSvgStr for Ellipse<T> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        format!(
+            r#"<ellipse cx="{x}" cy="{y}" rx="{radius_x}" ry="{radius_y}"{style}/>{labels}"#,
+            x = Num(self.center.x),
+            y = Num(self.center.y),
+            radius_x = Num(self.radius_x),
+            radius_y = Num(self.radius_y),
+            style = style,
+            labels = center_labels(self.center, style),
+        )
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        let stroke_width = style.stroke_width.unwrap_or(1.0) as f64;
+        let cast = |value: T| -> Option<f64> { NumCast::from(value) };
+        let (x, y, radius_x, radius_y) = match (
+            cast(self.center.x),
+            cast(self.center.y),
+            cast(self.radius_x),
+            cast(self.radius_y),
+        ) {
+            (Some(x), Some(y), Some(radius_x), Some(radius_y)) => {
+                (x, y, radius_x + stroke_width, radius_y + stroke_width)
+            }
+            _ => return ViewBox::default(),
+        };
+        ViewBox::new(x - radius_x, y - radius_y, x + radius_x, y + radius_y)
+    }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        style
+            .label
+            .as_ref()
+            .map(|label| {
+                Label::new(
+                    NumCast::from(self.center.x).unwrap_or(0.0),
+                    NumCast::from(self.center.y).unwrap_or(0.0),
+                    label,
+                    style,
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn is_finite(&self) -> bool {
+        let finite = |value: T| NumCast::from(value).is_some_and(f64::is_finite);
+        self.center.is_finite() && finite(self.radius_x) && finite(self.radius_y)
+    }
+}
+
+fn center_labels<T: CoordNum>(center: Coord<T>, style: &Style) -> String {
+    Ellipse::new(center, T::zero(), T::zero())
+        .labels(style)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Circle, Ellipse, ToSvg};
+    use geo_types::Coord;
+
+    #[test]
+    fn test_circle() {
+        let circle = Circle::new(Coord { x: 10.0, y: 20.0 }, 5.0);
+        let svg = circle.to_svg().to_string();
+        assert!(svg.contains(r#"viewBox="4 14 12 12""#));
+        assert!(svg.contains(r#"<circle cx="10.0" cy="20.0" r="5.0"/>"#));
+
+        let ellipse = Ellipse::new(Coord { x: 0, y: 0 }, 3, 2);
+        assert!(ellipse
+            .to_svg()
+            .to_string()
+            .contains(r#"<ellipse cx="0" cy="0" rx="3" ry="2"/>"#));
+    }
+}
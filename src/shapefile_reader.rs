@@ -0,0 +1,70 @@
+use geo_types::Geometry;
+use shapefile::dbase::FieldValue;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::Path;
+
+/// Geometry of a shapefile with the attributes of its `.dbf` record, from which it can be
+/// styled, e.g. with [`Styled`](crate::Styled).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeFeature {
+    pub geometry: Geometry<f64>,
+    pub attributes: BTreeMap<String, FieldValue>,
+}
+
+/// Reads the shapes of a `.shp` file and the records of the `.dbf` file next to it. Null
+/// shapes and multipatches that can't be converted to a geometry are left out, elevations and
+/// measures are dropped.
+pub fn read_shapefile<P: AsRef<Path>>(path: P) -> Result<Vec<ShapeFeature>, shapefile::Error> {
+    let mut reader = shapefile::Reader::from_path(path)?;
+    let mut features = Vec::new();
+    for shape_record in reader.iter_shapes_and_records() {
+        let (shape, record) = shape_record?;
+        if let Ok(geometry) = Geometry::try_from(shape) {
+            features.push(ShapeFeature {
+                geometry,
+                attributes: record.into_iter().collect(),
+            });
+        }
+    }
+    Ok(features)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_shapefile;
+    use geo_types::{line_string, Geometry, MultiLineString};
+    use shapefile::dbase::{FieldValue, Record, TableWriterBuilder};
+    use shapefile::{Point, Polyline, Writer};
+    use std::convert::TryInto;
+
+    #[test]
+    fn test_read_shapefile() {
+        let path = std::env::temp_dir().join(format!("geo-svg-roads-{}.shp", std::process::id()));
+        let table = TableWriterBuilder::new().add_character_field("NAME".try_into().unwrap(), 20);
+        let mut writer = Writer::from_path(&path, table).unwrap();
+        let mut record = Record::default();
+        record.insert("NAME".into(), FieldValue::Character(Some("Main St".into())));
+        let road = Polyline::new(vec![Point::new(0.0, 0.0), Point::new(10.0, 5.0)]);
+        writer.write_shape_and_record(&road, &record).unwrap();
+        drop(writer);
+
+        let features = read_shapefile(&path);
+        for extension in ["shp", "shx", "dbf"] {
+            std::fs::remove_file(path.with_extension(extension)).unwrap();
+        }
+        let features = features.unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(
+            features[0].geometry,
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 5.0)]
+            ]))
+        );
+        assert_eq!(
+            features[0].attributes.get("NAME"),
+            Some(&FieldValue::Character(Some("Main St".into())))
+        );
+        assert!(read_shapefile("missing.shp").is_err());
+    }
+}
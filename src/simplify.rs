@@ -0,0 +1,23 @@
+use geo::{MapCoords, Simplify};
+use geo_types::{Coord, CoordNum, LineString, Polygon};
+use num_traits::NumCast;
+
+fn to_f64<T: CoordNum>(coord: Coord<T>) -> Coord<f64> {
+    Coord {
+        x: NumCast::from(coord.x).unwrap_or(0.0),
+        y: NumCast::from(coord.y).unwrap_or(0.0),
+    }
+}
+
+/// Ramer–Douglas–Peucker simplification of the line string.
+pub(crate) fn simplify_line_string<T: CoordNum>(
+    line_string: &LineString<T>,
+    epsilon: f64,
+) -> LineString<f64> {
+    line_string.map_coords(to_f64).simplify(epsilon)
+}
+
+/// Ramer–Douglas–Peucker simplification of every ring of the polygon.
+pub(crate) fn simplify_polygon<T: CoordNum>(polygon: &Polygon<T>, epsilon: f64) -> Polygon<f64> {
+    polygon.map_coords(to_f64).simplify(epsilon)
+}
@@ -0,0 +1,169 @@
+use crate::{Color, PointType, Style, StyleSheet};
+use roxmltree::{Document, Node};
+
+/// First descendant element with the given local name, whatever its namespace.
+fn find<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.descendants()
+        .find(|child| child.is_element() && child.tag_name().name() == name)
+}
+
+/// Text of the direct child element with the given local name.
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.is_element() && child.tag_name().name() == name)?
+        .text()
+        .map(str::trim)
+}
+
+/// Value of a `<SvgParameter>`, or `<CssParameter>` before SLD 1.1, child of `node`.
+fn parameter<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| {
+            matches!(child.tag_name().name(), "SvgParameter" | "CssParameter")
+                && child.attribute("name") == Some(name)
+        })?
+        .text()
+        .map(str::trim)
+}
+
+fn number(value: Option<&str>) -> Option<f32> {
+    value?.parse().ok()
+}
+
+fn apply_fill(style: &mut Style, fill: Node) {
+    style.fill = Some(
+        parameter(fill, "fill")
+            .and_then(|color| color.parse().ok())
+            .unwrap_or(Color::Hex(0x808080)),
+    );
+    style.fill_opacity = number(parameter(fill, "fill-opacity"));
+}
+
+fn apply_stroke(style: &mut Style, stroke: Node) {
+    style.stroke_color = Some(
+        parameter(stroke, "stroke")
+            .and_then(|color| color.parse().ok())
+            .unwrap_or(Color::Named("black")),
+    );
+    style.stroke_width = Some(number(parameter(stroke, "stroke-width")).unwrap_or(1.0));
+    style.stroke_opacity = number(parameter(stroke, "stroke-opacity"));
+}
+
+fn apply_symbolizer(style: &mut Style, symbolizer: Node) {
+    match symbolizer.tag_name().name() {
+        "PointSymbolizer" => {
+            if let Some(graphic) = find(symbolizer, "Graphic") {
+                if let Some(size) = number(child_text(graphic, "Size")) {
+                    style.radius = size / 2.0;
+                }
+                if let Some(mark) = find(graphic, "Mark") {
+                    style.point_type = match child_text(mark, "WellKnownName") {
+                        Some("square") => Some(PointType::Square),
+                        Some("triangle") => Some(PointType::Triangle),
+                        Some("star") => Some(PointType::Star),
+                        Some("cross") => Some(PointType::Cross),
+                        Some("x") => Some(PointType::X),
+                        _ => Some(PointType::Circle),
+                    };
+                    if let Some(fill) = find(mark, "Fill") {
+                        apply_fill(style, fill);
+                    }
+                    if let Some(stroke) = find(mark, "Stroke") {
+                        apply_stroke(style, stroke);
+                    }
+                }
+            }
+        }
+        "LineSymbolizer" => {
+            style.fill = Some(Color::Named("none"));
+            if let Some(stroke) = find(symbolizer, "Stroke") {
+                apply_stroke(style, stroke);
+            }
+        }
+        "PolygonSymbolizer" => {
+            if let Some(fill) = find(symbolizer, "Fill") {
+                apply_fill(style, fill);
+            }
+            if let Some(stroke) = find(symbolizer, "Stroke") {
+                apply_stroke(style, stroke);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl StyleSheet {
+    /// Converts the rules of a Styled Layer Descriptor into styles named after the rule names,
+    /// or titles, falling back to `rule-N` for the N-th anonymous rule. The point, line and
+    /// polygon symbolizers of a rule are merged into its style, filters, scale denominators
+    /// and external graphics are ignored.
+    pub fn from_sld(xml: &str) -> Result<Self, roxmltree::Error> {
+        let document = Document::parse(xml)?;
+        let mut sheet = StyleSheet::new();
+        let rules = document
+            .descendants()
+            .filter(|node| node.is_element() && node.tag_name().name() == "Rule");
+        for (index, rule) in rules.enumerate() {
+            let mut style = Style::default();
+            for symbolizer in rule.children().filter(Node::is_element) {
+                apply_symbolizer(&mut style, symbolizer);
+            }
+            let name = child_text(rule, "Name")
+                .or_else(|| child_text(rule, "Title"))
+                .map(String::from)
+                .unwrap_or_else(|| format!("rule-{}", index));
+            sheet.insert(&name, style);
+        }
+        Ok(sheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, PointType, StyleSheet};
+
+    #[test]
+    fn test_from_sld() {
+        let sheet = StyleSheet::from_sld(
+            r##"<StyledLayerDescriptor version="1.0.0" xmlns="http://www.opengis.net/sld">
+                <NamedLayer><UserStyle><FeatureTypeStyle>
+                    <Rule>
+                        <Name>lakes</Name>
+                        <PolygonSymbolizer>
+                            <Fill>
+                                <CssParameter name="fill">#0000FF</CssParameter>
+                                <CssParameter name="fill-opacity">0.5</CssParameter>
+                            </Fill>
+                            <Stroke><CssParameter name="stroke">navy</CssParameter></Stroke>
+                        </PolygonSymbolizer>
+                    </Rule>
+                    <Rule>
+                        <LineSymbolizer>
+                            <Stroke><CssParameter name="stroke-width">3</CssParameter></Stroke>
+                        </LineSymbolizer>
+                    </Rule>
+                    <Rule>
+                        <Title>Towns</Title>
+                        <PointSymbolizer><Graphic>
+                            <Mark><WellKnownName>star</WellKnownName></Mark>
+                            <Size>8</Size>
+                        </Graphic></PointSymbolizer>
+                    </Rule>
+                </FeatureTypeStyle></UserStyle></NamedLayer>
+            </StyledLayerDescriptor>"##,
+        )
+        .unwrap();
+        let lakes = sheet.get("lakes").unwrap();
+        assert_eq!(lakes.fill, Some(Color::Hex(0x0000FF)));
+        assert_eq!(lakes.fill_opacity, Some(0.5));
+        assert_eq!(lakes.stroke_color, Some(Color::Named("navy")));
+        assert_eq!(lakes.stroke_width, Some(1.0));
+        let line = sheet.get("rule-1").unwrap();
+        assert_eq!(line.stroke_width, Some(3.0));
+        assert_eq!(line.fill, Some(Color::Named("none")));
+        let towns = sheet.get("Towns").unwrap();
+        assert_eq!(towns.point_type, Some(PointType::Star));
+        assert_eq!(towns.radius, 4.0);
+        assert!(StyleSheet::from_sld("<Rule>").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::number::Num;
+use crate::Smoothing;
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use geo_types::{CoordNum, LineString};
+use num_traits::NumCast;
+
+/// Path data of a curve passing through (`CatmullRom`) or guided by (`Quadratic`) the
+/// vertices of the line string.
+pub(crate) fn smooth_path<T: CoordNum>(
+    line_string: &LineString<T>,
+    smoothing: Smoothing,
+) -> String {
+    let points = line_string
+        .coords()
+        .map(|coord| {
+            (
+                NumCast::from(coord.x).unwrap_or(0f64),
+                NumCast::from(coord.y).unwrap_or(0f64),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut path = String::new();
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return path,
+    };
+    write!(path, "M {} {}", Num(first.0), Num(first.1)).unwrap();
+    if points.len() < 3 {
+        for (x, y) in &points[1..] {
+            write!(path, " L {} {}", Num(*x), Num(*y)).unwrap();
+        }
+        return path;
+    }
+
+    match smoothing {
+        // uniform Catmull-Rom spline, converted to cubic bezier segments
+        Smoothing::CatmullRom => {
+            for i in 0..points.len() - 1 {
+                let p0 = points[i.saturating_sub(1)];
+                let (p1, p2) = (points[i], points[i + 1]);
+                let p3 = points[(i + 2).min(points.len() - 1)];
+                write!(
+                    path,
+                    " C {} {} {} {} {} {}",
+                    Num(p1.0 + (p2.0 - p0.0) / 6.0),
+                    Num(p1.1 + (p2.1 - p0.1) / 6.0),
+                    Num(p2.0 - (p3.0 - p1.0) / 6.0),
+                    Num(p2.1 - (p3.1 - p1.1) / 6.0),
+                    Num(p2.0),
+                    Num(p2.1),
+                )
+                .unwrap();
+            }
+        }
+        // curves between segment midpoints, using the vertices as control points
+        Smoothing::Quadratic => {
+            let midpoint = |a: (f64, f64), b: (f64, f64)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+            let start = midpoint(points[0], points[1]);
+            write!(path, " L {} {}", Num(start.0), Num(start.1)).unwrap();
+            for window in points.windows(3) {
+                let end = midpoint(window[1], window[2]);
+                write!(
+                    path,
+                    " Q {} {} {} {}",
+                    Num(window[1].0),
+                    Num(window[1].1),
+                    Num(end.0),
+                    Num(end.1)
+                )
+                .unwrap();
+            }
+            write!(path, " L {} {}", Num(last.0), Num(last.1)).unwrap();
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::smooth_path;
+    use crate::Smoothing;
+    use geo_types::LineString;
+
+    #[test]
+    fn test_smooth_path() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (6.0, 6.0), (12.0, 0.0)]);
+        assert_eq!(
+            smooth_path(&line_string, Smoothing::Quadratic),
+            "M 0.0 0.0 L 3.0 3.0 Q 6.0 6.0 9.0 3.0 L 12.0 0.0"
+        );
+        assert_eq!(
+            smooth_path(&line_string, Smoothing::CatmullRom),
+            "M 0.0 0.0 C 1.0 1.0 4.0 6.0 6.0 6.0 C 8.0 6.0 11.0 1.0 12.0 0.0"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+use geo_types::{Coord, CoordNum, LineString, Polygon};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+fn snap_coord<T: CoordNum>(coord: Coord<T>, step: f64) -> Coord<f64> {
+    let snap = |value: T| {
+        let value: f64 = NumCast::from(value).unwrap_or(f64::NAN);
+        (value / step).round() * step
+    };
+    Coord {
+        x: snap(coord.x),
+        y: snap(coord.y),
+    }
+}
+
+/// Rounds the coordinates to the nearest multiple of `step`, dropping the vertices which then
+/// fall on the previous one.
+pub(crate) fn snap_line_string<T: CoordNum>(
+    line_string: &LineString<T>,
+    step: f64,
+) -> LineString<f64> {
+    let mut coords: Vec<Coord<f64>> = line_string
+        .coords()
+        .map(|coord| snap_coord(*coord, step))
+        .collect();
+    coords.dedup();
+    LineString(coords)
+}
+
+/// Snaps every ring of the polygon, see [`snap_line_string`].
+pub(crate) fn snap_polygon<T: CoordNum>(polygon: &Polygon<T>, step: f64) -> Polygon<f64> {
+    Polygon::new(
+        snap_line_string(polygon.exterior(), step),
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| snap_line_string(interior, step))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snap_line_string, snap_polygon};
+    use geo_types::{LineString, Polygon};
+
+    #[test]
+    fn test_snap_line_string() {
+        let line_string = LineString::from(vec![(0.1, 0.2), (0.4, -0.3), (2.6, 1.4), (3.1, 0.9)]);
+        assert_eq!(
+            snap_line_string(&line_string, 1.0),
+            LineString::from(vec![(0.0, 0.0), (3.0, 1.0)])
+        );
+        assert_eq!(
+            snap_line_string(&line_string, 0.5),
+            LineString::from(vec![(0.0, 0.0), (0.5, -0.5), (2.5, 1.5), (3.0, 1.0)])
+        );
+    }
+
+    #[test]
+    fn test_snap_polygon() {
+        let square = LineString::from(vec![(0.1, 0.0), (9.9, 0.1), (10.0, 10.2), (0.0, 9.8)]);
+        let snapped = snap_polygon(&Polygon::new(square, vec![]), 1.0);
+        assert_eq!(
+            snapped.exterior(),
+            &LineString::from(vec![
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (0.0, 10.0),
+                (0.0, 0.0)
+            ])
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::aspect::PreserveAspectRatio;
+use crate::svg::{document_start, extent_rect, Namespaces};
+use crate::{Color, Style, ToSvgStr, ViewBox};
+use geo_types::Geometry;
+use std::io::{Result, Write};
+
+/// Settings of [`render_iter`], which can't look at the features before writing them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderOptions {
+    /// Extent of the features, which sets the viewbox of the document.
+    pub extent: ViewBox,
+    pub margin: f64,
+    pub background: Option<Color>,
+    /// The xlink namespace is declared, for the `xlink:href` of features drawn with the svg
+    /// 1.1 profile. Without a first pass, it's declared in case some are.
+    pub xlink_namespace: bool,
+}
+
+impl RenderOptions {
+    pub fn new(extent: ViewBox) -> Self {
+        Self {
+            extent,
+            margin: 0.0,
+            background: None,
+            xlink_namespace: true,
+        }
+    }
+
+    /// Computes the extent and the namespaces in a first pass over the features, by reading
+    /// them twice from the source, without keeping them.
+    pub fn from_features<I>(features: I) -> Self
+    where
+        I: IntoIterator<Item = (Geometry<f64>, Style)>,
+    {
+        let (extent, xlink_namespace) = features.into_iter().fold(
+            (ViewBox::default(), false),
+            |(extent, xlink), (geometry, style)| {
+                (
+                    extent.add(&geometry.viewbox(&style)),
+                    xlink || geometry.uses_xlink(&style),
+                )
+            },
+        );
+        Self {
+            xlink_namespace,
+            ..Self::new(extent)
+        }
+    }
+
+    pub fn with_margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+}
+
+/// Writer handing the document to the underlying writer in chunks of about `chunk_size`
+/// bytes, each made of whole elements, so that peak memory stays constant whatever the number
+/// of features. Elements larger than a chunk are passed on directly.
+///
+/// ```
+/// # use geo_svg::{render_iter, ChunkedWriter, RenderOptions, ViewBox};
+/// # fn main() -> std::io::Result<()> {
+/// let mut output = Vec::new();
+/// let options = RenderOptions::new(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+/// render_iter(vec![], ChunkedWriter::new(&mut output, 64 * 1024), &options)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChunkedWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+}
+
+impl<W: Write> ChunkedWriter<W> {
+    pub fn new(writer: W, chunk_size: usize) -> Self {
+        Self {
+            writer,
+            buffer: Vec::with_capacity(chunk_size),
+            chunk_size,
+        }
+    }
+
+    /// Writes the pending elements and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.write_buffer()?;
+        let Self { writer, .. } = self;
+        Ok(writer)
+    }
+
+    fn write_buffer(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            self.writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ChunkedWriter<W> {
+    /// Takes the whole element, which is only split by the underlying writer.
+    fn write(&mut self, element: &[u8]) -> Result<usize> {
+        if self.buffer.len() + element.len() > self.chunk_size {
+            self.write_buffer()?;
+        }
+        if element.len() > self.chunk_size {
+            self.writer.write_all(element)?;
+        } else {
+            self.buffer.extend_from_slice(element);
+        }
+        Ok(element.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.write_buffer()?;
+        self.writer.flush()
+    }
+}
+
+/// Writes a document of the features one element at a time, so that memory use doesn't grow
+/// with their number, e.g. when rendering the rows of a database cursor.
+///
+/// Features are drawn independently: label collision, symbols and style filters, which need
+/// the whole document, aren't supported.
+pub fn render_iter<I, W>(features: I, mut writer: W, options: &RenderOptions) -> Result<()>
+where
+    I: IntoIterator<Item = (Geometry<f64>, Style)>,
+    W: Write,
+{
+    write_document_start(&mut writer, options)?;
+    for (geometry, style) in features {
+        writer.write_all(geometry.to_svg_str(&style).as_bytes())?;
+    }
+    writer.write_all(b"</svg>")?;
+    writer.flush()
+}
+
+/// Writes the opening tag of the document and its background.
+pub(crate) fn write_document_start<W: Write>(
+    writer: &mut W,
+    options: &RenderOptions,
+) -> Result<()> {
+    let viewbox = options.extent.with_margin(options.margin);
+    let namespaces = Namespaces {
+        xlink: options.xlink_namespace,
+        inkscape: false,
+    };
+    let start = document_start(&viewbox, PreserveAspectRatio::default(), namespaces, "");
+    writer.write_all(start.as_bytes())?;
+    if let Some(color) = options.background {
+        let background = extent_rect(&viewbox, &format!(r#" fill="{}""#, color));
+        writer.write_all(background.as_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_iter, ChunkedWriter, RenderOptions};
+    use crate::{Color, Style, SvgProfile, ToSvg};
+    use geo_types::{Geometry, LineString, Point, Rect};
+
+    #[test]
+    fn test_render_iter() {
+        let features = || {
+            (0..3).map(|x| {
+                let style = Style {
+                    fill: Some(Color::Named("red")),
+                    ..Style::default()
+                };
+                (Geometry::Point(Point::new(x as f64, 0.0)), style)
+            })
+        };
+        let mut output = Vec::new();
+        render_iter(
+            features(),
+            &mut output,
+            &RenderOptions::from_features(features()),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let points: Vec<_> = features().map(|(point, _)| point).collect();
+        let svg = points.to_svg().with_fill_color(Color::Named("red"));
+        assert_eq!(output, svg.to_string());
+
+        let mut output = Vec::new();
+        let extent = Rect::new((0.0, 0.0), (10.0, 10.0)).to_svg().viewbox();
+        let options = RenderOptions::new(extent).with_background(Color::Named("white"));
+        render_iter(features(), &mut output, &options).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains(
+            r#"viewBox="-1 -1 12 12"><rect x="-1" y="-1" width="12" height="12" fill="white"/><circle"#
+        ));
+    }
+
+    #[test]
+    fn test_render_iter_namespaces() {
+        let road = Geometry::LineString(LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]));
+        let style = |profile| Style {
+            id: Some("road".into()),
+            text: Some("Main St".into()),
+            profile,
+            ..Style::default()
+        };
+        let render = |options: &RenderOptions, profile| {
+            let mut output = Vec::new();
+            render_iter(vec![(road.clone(), style(profile))], &mut output, options).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        let features = |profile| vec![(road.clone(), style(profile))];
+
+        let options = RenderOptions::from_features(features(SvgProfile::Svg11));
+        assert!(render(&options, SvgProfile::Svg11)
+            .starts_with(r#"<svg xmlns:xlink="http://www.w3.org/1999/xlink" xmlns="#));
+        let options = RenderOptions::from_features(features(SvgProfile::Svg2));
+        assert!(!render(&options, SvgProfile::Svg2).contains("xmlns:xlink"));
+        // declared in case the features need it
+        let options = RenderOptions::new(options.extent);
+        assert!(render(&options, SvgProfile::Svg11).contains("xmlns:xlink"));
+    }
+
+    /// Records the size of every write.
+    #[derive(Default)]
+    struct Recorder {
+        output: Vec<u8>,
+        writes: Vec<usize>,
+    }
+
+    impl std::io::Write for Recorder {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.output.extend_from_slice(bytes);
+            self.writes.push(bytes.len());
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_chunked_writer() {
+        let features = (0..100).map(|x| {
+            let point = Geometry::Point(Point::new(x as f64, 0.0));
+            (point, Style::default())
+        });
+        let options = RenderOptions::new(Rect::new((0.0, 0.0), (100.0, 0.0)).to_svg().viewbox());
+        let mut expected = Vec::new();
+        render_iter(features.clone(), &mut expected, &options).unwrap();
+
+        let mut recorder = Recorder::default();
+        render_iter(features, ChunkedWriter::new(&mut recorder, 256), &options).unwrap();
+        assert_eq!(recorder.output, expected);
+        assert!(recorder.writes.len() > 1);
+        assert!(recorder.writes.iter().all(|&size| size <= 256));
+
+        let mut writer = ChunkedWriter::new(Vec::new(), 4);
+        std::io::Write::write_all(&mut writer, b"<g>").unwrap();
+        std::io::Write::write_all(&mut writer, b"<path/>").unwrap();
+        assert_eq!(writer.into_inner().unwrap(), b"<g><path/>");
+    }
+}
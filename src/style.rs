@@ -1,20 +1,300 @@
-use crate::Color;
-use std::fmt::{Display, Formatter, Result};
+use crate::escape::Escaped;
+use crate::{Color, Projection};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointType {
     Circle,
     Symbol,
     Text,
     Poi,
+    Square,
+    Triangle,
+    Diamond,
+    Star,
+    Cross,
+    X,
+    Pin,
+    Glyph(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ElementMode {
+    /// Every line and ring is a `<path>`.
+    Path,
+    /// Line strings are `<polyline>` and polygons without holes are `<polygon>` elements.
+    Primitive,
+}
+
+/// Effect applied to the rendered geometries, its lengths are in map units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Filter {
+    DropShadow {
+        dx: f32,
+        dy: f32,
+        blur: f32,
+        color: Color,
+    },
+    /// Blur with the given standard deviation.
+    GaussianBlur(f32),
+}
+
+/// Gradient painting a mask, see [`Svg::with_gradient_mask`](crate::Svg::with_gradient_mask).
+/// Its positions are fractions of the bounding box of the masked content and its stops are
+/// offsets from 0 to 1 along the gradient with their color.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+    /// From `(x1, y1)` to `(x2, y2)`.
+    Linear {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        stops: Vec<(f32, Color)>,
+    },
+    /// From the center `(cx, cy)` out to the radius `r`.
+    Radial {
+        cx: f32,
+        cy: f32,
+        r: f32,
+        stops: Vec<(f32, Color)>,
+    },
+}
+
+impl Gradient {
+    /// Writes the gradient element with the given id.
+    pub(crate) fn write_element(&self, fmt: &mut Formatter, id: &str) -> Result {
+        let (tag, stops) = match self {
+            Gradient::Linear {
+                x1,
+                y1,
+                x2,
+                y2,
+                stops,
+            } => {
+                write!(
+                    fmt,
+                    r#"<linearGradient id="{}" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+                    id, x1, y1, x2, y2
+                )?;
+                ("linearGradient", stops)
+            }
+            Gradient::Radial { cx, cy, r, stops } => {
+                write!(
+                    fmt,
+                    r#"<radialGradient id="{}" cx="{}" cy="{}" r="{}">"#,
+                    id, cx, cy, r
+                )?;
+                ("radialGradient", stops)
+            }
+        };
+        for (offset, color) in stops {
+            write!(fmt, r#"<stop offset="{}" stop-color="{}"/>"#, offset, color)?;
+        }
+        write!(fmt, "</{}>", tag)
+    }
+}
+
+/// Version of the svg specification the output is written for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SvgProfile {
+    /// References use `xlink:href` and text halos are a copy of the text drawn behind it,
+    /// for older renderers.
+    Svg11,
+    /// References use `href` and text halos use `paint-order`.
+    Svg2,
+}
+
+impl SvgProfile {
+    /// Name of the attribute referencing another element or an external resource.
+    pub(crate) fn href(self) -> &'static str {
+        match self {
+            SvgProfile::Svg11 => "xlink:href",
+            SvgProfile::Svg2 => "href",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathEncoding {
+    /// Path commands use absolute coordinates (`M`, `L`).
+    Absolute,
+    /// Path commands use coordinates relative to the previous point (`m`, `l`), which is
+    /// usually shorter.
+    Relative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Smoothing {
+    /// Cubic curve passing through every vertex.
+    CatmullRom,
+    /// Quadratic curve using the vertices as control points, cutting the corners.
+    Quadratic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RadiusUnit {
+    /// The radius is in the same units as the geometries, markers scale with the map.
+    Map,
+    /// The radius is in screen pixels, markers keep their size when the map is scaled.
+    /// Only circles and squares support it, other point types fall back to map units.
+    Pixels,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontWeight {
+    Normal,
+    Bold,
+    Bolder,
+    Lighter,
+    Weight(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Display for Filter {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Filter::DropShadow {
+                dx,
+                dy,
+                blur,
+                color,
+            } => write!(
+                fmt,
+                r#"<feDropShadow dx="{}" dy="{}" stdDeviation="{}" flood-color="{}"/>"#,
+                dx, dy, blur, color
+            ),
+            Filter::GaussianBlur(std_deviation) => {
+                write!(fmt, r#"<feGaussianBlur stdDeviation="{}"/>"#, std_deviation)
+            }
+        }
+    }
+}
+
+impl Display for FontWeight {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            FontWeight::Normal => write!(fmt, "normal"),
+            FontWeight::Bold => write!(fmt, "bold"),
+            FontWeight::Bolder => write!(fmt, "bolder"),
+            FontWeight::Lighter => write!(fmt, "lighter"),
+            FontWeight::Weight(weight) => write!(fmt, "{}", weight),
+        }
+    }
+}
+
+impl Display for FontStyle {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            FontStyle::Normal => write!(fmt, "normal"),
+            FontStyle::Italic => write!(fmt, "italic"),
+            FontStyle::Oblique => write!(fmt, "oblique"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DominantBaseline {
+    Auto,
+    Alphabetic,
+    Central,
+    Middle,
+    Hanging,
+    Mathematical,
+    Ideographic,
+    TextBeforeEdge,
+    TextAfterEdge,
+}
+
+impl TextAnchor {
+    /// Fraction of the text width that lies before the anchor point.
+    pub(crate) fn offset(self) -> f64 {
+        match self {
+            TextAnchor::Start => 0.0,
+            TextAnchor::Middle => 0.5,
+            TextAnchor::End => 1.0,
+        }
+    }
+}
+
+impl DominantBaseline {
+    /// Fraction of the text height that lies above the anchor point.
+    pub(crate) fn offset(self) -> f64 {
+        match self {
+            DominantBaseline::Central | DominantBaseline::Middle => 0.5,
+            DominantBaseline::Hanging | DominantBaseline::TextBeforeEdge => 0.0,
+            DominantBaseline::Auto
+            | DominantBaseline::Alphabetic
+            | DominantBaseline::Mathematical
+            | DominantBaseline::Ideographic
+            | DominantBaseline::TextAfterEdge => 1.0,
+        }
+    }
+}
+
+impl Display for TextAnchor {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            TextAnchor::Start => write!(fmt, "start"),
+            TextAnchor::Middle => write!(fmt, "middle"),
+            TextAnchor::End => write!(fmt, "end"),
+        }
+    }
+}
+
+impl Display for DominantBaseline {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            DominantBaseline::Auto => write!(fmt, "auto"),
+            DominantBaseline::Alphabetic => write!(fmt, "alphabetic"),
+            DominantBaseline::Central => write!(fmt, "central"),
+            DominantBaseline::Middle => write!(fmt, "middle"),
+            DominantBaseline::Hanging => write!(fmt, "hanging"),
+            DominantBaseline::Mathematical => write!(fmt, "mathematical"),
+            DominantBaseline::Ideographic => write!(fmt, "ideographic"),
+            DominantBaseline::TextBeforeEdge => write!(fmt, "text-before-edge"),
+            DominantBaseline::TextAfterEdge => write!(fmt, "text-after-edge"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform {
     transform_functions: Vec<TransformFn>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransformFn {
     Matrix(f64, f64, f64, f64, f64, f64),
     Translate(f64, Option<f64>),
@@ -30,6 +310,13 @@ impl Transform {
             transform_functions,
         }
     }
+
+    /// Appends a function. As in the attribute, the last function is the first one applied to
+    /// the coordinates.
+    pub fn then(mut self, transform_function: TransformFn) -> Self {
+        self.transform_functions.push(transform_function);
+        self
+    }
 }
 
 impl Display for Transform {
@@ -38,6 +325,9 @@ impl Display for Transform {
             .transform_functions
             .iter()
             .fold("".to_string(), |mut acc, f| {
+                if !acc.is_empty() {
+                    acc.push(' ');
+                }
                 acc.push_str(&f.to_string());
                 acc
             });
@@ -75,6 +365,8 @@ impl Display for TransformFn {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
     pub opacity: Option<f32>,
     pub fill: Option<Color>,
@@ -83,16 +375,66 @@ pub struct Style {
     pub stroke_width: Option<f32>,
     pub stroke_opacity: Option<f32>,
     pub radius: f32,
+    pub radius_unit: RadiusUnit,
     pub css_classes: Option<String>,
     pub id: Option<String>,
     pub point_type: Option<PointType>,
+    pub symbol: Option<String>,
+    pub icon_href: Option<String>,
     pub icon_svg_path: Option<String>,
     pub icon_svg_viewbox: Option<(i32, i32, i32, i32)>,
     pub icon_svg_width_height: Option<(i32, i32)>,
     pub text: Option<String>,
     pub text_start_offset: Option<f64>,
     pub text_classes: Option<String>,
+    pub label: Option<String>,
+    pub label_priority: i32,
+    pub text_halo: Option<(Color, f32)>,
+    pub font_family: Option<String>,
+    pub font_size: Option<f32>,
+    pub font_weight: Option<FontWeight>,
+    pub font_style: Option<FontStyle>,
+    pub text_anchor: Option<TextAnchor>,
+    pub dominant_baseline: Option<DominantBaseline>,
+    pub line_height: Option<f32>,
     pub transform: Option<Transform>,
+    pub smoothing: Option<Smoothing>,
+    pub element_mode: ElementMode,
+    pub path_encoding: PathEncoding,
+    pub corner_radius: Option<f32>,
+    pub filter: Option<Filter>,
+    /// Projections are code, they are left out of serialized styles.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub projection: Option<Projection>,
+    pub great_circle_step: Option<f64>,
+    pub wrap_antimeridian: bool,
+    /// Coordinates are (y, x), e.g. latitude first, and are exchanged before rendering.
+    pub swap_axes: bool,
+    pub snap_grid: Option<f64>,
+    pub debug_vertices: bool,
+    pub debug_bbox: bool,
+    /// Style of the points drawn on the centroid of every line string and polygon.
+    pub centroid_marker: Option<Box<Style>>,
+    /// Distance between the arrows drawn along line strings, in map units.
+    pub direction_arrows: Option<f64>,
+    /// Unit written after the segment lengths and their number of decimals.
+    pub segment_lengths: Option<(String, usize)>,
+    /// Text of the area labels of polygons, see [`Svg::with_area_label`].
+    ///
+    /// [`Svg::with_area_label`]: crate::Svg::with_area_label
+    pub area_label: Option<String>,
+    /// Number of decimals of the coordinates written next to every vertex.
+    pub coordinate_labels: Option<usize>,
+    pub normalize_winding: bool,
+    /// Style of the outlines of polygon holes, which are then drawn apart from the exterior.
+    pub hole_style: Option<Box<Style>>,
+    pub profile: SvgProfile,
+    /// Area labels give the area on the ellipsoid of lon/lat polygons, in square meters. It
+    /// needs the `geo` feature, they give the planar area without it.
+    pub geodesic_area: bool,
+    /// Tolerance of the simplification of line strings and polygons, which needs the `geo`
+    /// feature: they're drawn as they are without it.
+    pub simplify_epsilon: Option<f64>,
 }
 
 impl Default for Style {
@@ -105,20 +447,66 @@ impl Default for Style {
             stroke_width: None,
             stroke_opacity: None,
             radius: 1.0,
+            radius_unit: RadiusUnit::Map,
             css_classes: None,
             id: None,
             point_type: None,
+            symbol: None,
+            icon_href: None,
             icon_svg_path: None,
             icon_svg_viewbox: None,
             icon_svg_width_height: None,
             text: None,
             text_start_offset: None,
             text_classes: None,
+            label: None,
+            label_priority: 0,
+            text_halo: None,
+            font_family: None,
+            font_size: None,
+            font_weight: None,
+            font_style: None,
+            text_anchor: None,
+            dominant_baseline: None,
+            line_height: None,
             transform: None,
+            smoothing: None,
+            element_mode: ElementMode::Path,
+            path_encoding: PathEncoding::Absolute,
+            corner_radius: None,
+            filter: None,
+            projection: None,
+            great_circle_step: None,
+            wrap_antimeridian: false,
+            swap_axes: false,
+            snap_grid: None,
+            debug_vertices: false,
+            debug_bbox: false,
+            centroid_marker: None,
+            direction_arrows: None,
+            segment_lengths: None,
+            area_label: None,
+            coordinate_labels: None,
+            normalize_winding: false,
+            hole_style: None,
+            profile: SvgProfile::Svg2,
+            geodesic_area: false,
+            simplify_epsilon: None,
         }
     }
 }
 
+impl Style {
+    /// Whether geometries drawn with the style reference other elements through
+    /// `xlink:href`, i.e. text along their path or symbols of the svg 1.1 profile.
+    pub(crate) fn uses_xlink(&self) -> bool {
+        let text_path = self.text.is_some() && self.id.is_some();
+        let symbol = self.point_type == Some(PointType::Symbol)
+            && (self.symbol.is_some() || self.icon_href.is_some());
+        self.profile == SvgProfile::Svg11 && (text_path || symbol)
+    }
+}
+
 impl Display for Style {
     fn fmt(&self, fmt: &mut Formatter) -> Result {
         if let Some(opacity) = self.opacity {
@@ -139,11 +527,11 @@ impl Display for Style {
         if let Some(stroke_opacity) = self.stroke_opacity {
             write!(fmt, r#" stroke-opacity="{}""#, stroke_opacity)?;
         }
-        if let Some(css_classes) = self.css_classes.clone() {
-            write!(fmt, r#" class="{}""#, css_classes)?;
+        if let Some(css_classes) = &self.css_classes {
+            write!(fmt, r#" class="{}""#, Escaped(css_classes))?;
         }
-        if let Some(id) = self.id.clone() {
-            write!(fmt, r#" id="{}""#, id)?;
+        if let Some(id) = &self.id {
+            write!(fmt, r#" id="{}""#, Escaped(id))?;
         }
         if let Some(transform) = &self.transform {
             write!(fmt, r#" transform="{}""#, transform)?;
@@ -151,3 +539,58 @@ impl Display for Style {
         Ok(())
     }
 }
+
+/// Renders a text with `render`, followed by a copy without the halo when the profile can't
+/// draw the halo behind the text.
+pub(crate) fn with_halo_copy(style: &Style, render: impl Fn(&Style) -> String) -> String {
+    let text = render(style);
+    match (style.text_halo, style.profile) {
+        (Some(_), SvgProfile::Svg11) => {
+            text + &render(&Style {
+                text_halo: None,
+                stroke_color: None,
+                stroke_width: None,
+                stroke_opacity: None,
+                ..style.clone()
+            })
+        }
+        _ => text,
+    }
+}
+
+/// Attributes specific to `<text>` elements.
+pub(crate) struct TextStyle<'a>(pub &'a Style);
+
+impl<'a> Display for TextStyle<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        if let Some(text_anchor) = self.0.text_anchor {
+            write!(fmt, r#" text-anchor="{}""#, text_anchor)?;
+        }
+        if let Some(dominant_baseline) = self.0.dominant_baseline {
+            write!(fmt, r#" dominant-baseline="{}""#, dominant_baseline)?;
+        }
+        if let Some(font_family) = &self.0.font_family {
+            write!(fmt, r#" font-family="{}""#, Escaped(font_family))?;
+        }
+        if let Some(font_size) = self.0.font_size {
+            write!(fmt, r#" font-size="{}""#, font_size)?;
+        }
+        if let Some(font_weight) = self.0.font_weight {
+            write!(fmt, r#" font-weight="{}""#, font_weight)?;
+        }
+        if let Some(font_style) = self.0.font_style {
+            write!(fmt, r#" font-style="{}""#, font_style)?;
+        }
+        if let Some((color, width)) = self.0.text_halo {
+            write!(
+                fmt,
+                r#" stroke="{}" stroke-width="{}" stroke-linejoin="round""#,
+                color, width
+            )?;
+            if self.0.profile == SvgProfile::Svg2 {
+                write!(fmt, r#" paint-order="stroke""#)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{Color, FontStyle, FontWeight, PointType, RadiusUnit, Style};
+use core::fmt::{Display, Formatter, Result};
+
+/// Style combination that would render broken or invisible svg.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleError {
+    NegativeRadius(f32),
+    NegativeStrokeWidth(f32),
+    /// An opacity, named by its attribute, isn't between 0 and 1.
+    OpacityOutOfRange(&'static str, f32),
+    /// Text along lines is drawn on a `<textPath>` referencing the line by its id.
+    TextPathWithoutId,
+    /// Pois are drawn from the icon path.
+    PoiWithoutIcon,
+}
+
+impl Display for StyleError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            StyleError::NegativeRadius(radius) => write!(fmt, "negative radius: {}", radius),
+            StyleError::NegativeStrokeWidth(width) => {
+                write!(fmt, "negative stroke width: {}", width)
+            }
+            StyleError::OpacityOutOfRange(attribute, opacity) => {
+                write!(fmt, "{} out of 0..1: {}", attribute, opacity)
+            }
+            StyleError::TextPathWithoutId => write!(fmt, "text along a path needs an id"),
+            StyleError::PoiWithoutIcon => write!(fmt, "poi without icon path"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StyleError {}
+
+impl Style {
+    /// Checks that the style renders valid svg, see [`StyleError`].
+    pub fn validate(&self) -> core::result::Result<(), StyleError> {
+        if self.radius < 0.0 {
+            return Err(StyleError::NegativeRadius(self.radius));
+        }
+        if let Some(width) = self.stroke_width.filter(|width| *width < 0.0) {
+            return Err(StyleError::NegativeStrokeWidth(width));
+        }
+        let opacities = [
+            ("opacity", self.opacity),
+            ("fill-opacity", self.fill_opacity),
+            ("stroke-opacity", self.stroke_opacity),
+        ];
+        for (attribute, opacity) in opacities.iter() {
+            if let Some(opacity) = opacity.filter(|opacity| !(0.0..=1.0).contains(opacity)) {
+                return Err(StyleError::OpacityOutOfRange(attribute, opacity));
+            }
+        }
+        let point_text = self.point_type == Some(PointType::Text);
+        if self.text.is_some() && self.id.is_none() && !point_text {
+            return Err(StyleError::TextPathWithoutId);
+        }
+        if self.point_type == Some(PointType::Poi) && self.icon_svg_path.is_none() {
+            return Err(StyleError::PoiWithoutIcon);
+        }
+        Ok(())
+    }
+}
+
+/// Builds a [`Style`] checked by [`Style::validate`].
+///
+/// ```
+/// use geo_svg::{Color, StyleBuilder, StyleError};
+///
+/// let style = StyleBuilder::new()
+///     .with_fill_color(Color::Named("red"))
+///     .with_fill_opacity(0.5)
+///     .build();
+/// assert!(style.is_ok());
+/// let style = StyleBuilder::new().with_radius(-1.0).build();
+/// assert_eq!(style, Err(StyleError::NegativeRadius(-1.0)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StyleBuilder {
+    style: Style,
+}
+
+impl From<Style> for StyleBuilder {
+    fn from(style: Style) -> Self {
+        Self { style }
+    }
+}
+
+impl StyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.style.opacity = Some(opacity);
+        self
+    }
+
+    pub fn with_fill_color(mut self, fill: Color) -> Self {
+        self.style.fill = Some(fill);
+        self
+    }
+
+    pub fn with_fill_opacity(mut self, fill_opacity: f32) -> Self {
+        self.style.fill_opacity = Some(fill_opacity);
+        self
+    }
+
+    pub fn with_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.style.stroke_color = Some(stroke_color);
+        self
+    }
+
+    pub fn with_stroke_width(mut self, stroke_width: f32) -> Self {
+        self.style.stroke_width = Some(stroke_width);
+        self
+    }
+
+    pub fn with_stroke_opacity(mut self, stroke_opacity: f32) -> Self {
+        self.style.stroke_opacity = Some(stroke_opacity);
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.style.radius = radius;
+        self
+    }
+
+    pub fn with_radius_unit(mut self, radius_unit: RadiusUnit) -> Self {
+        self.style.radius_unit = radius_unit;
+        self
+    }
+
+    pub fn with_css_classes(mut self, css_classes: &str) -> Self {
+        self.style.css_classes = Some(css_classes.into());
+        self
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.style.id = Some(id.into());
+        self
+    }
+
+    pub fn with_point_type(mut self, point_type: PointType) -> Self {
+        self.style.point_type = Some(point_type);
+        self
+    }
+
+    pub fn with_icon_svg_path(
+        mut self,
+        path: &str,
+        view_box: (i32, i32, i32, i32),
+        width_height: (i32, i32),
+    ) -> Self {
+        self.style.icon_svg_path = Some(path.into());
+        self.style.icon_svg_viewbox = Some(view_box);
+        self.style.icon_svg_width_height = Some(width_height);
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.style.text = Some(text.into());
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.style.label = Some(label.into());
+        self
+    }
+
+    pub fn with_font_family(mut self, font_family: &str) -> Self {
+        self.style.font_family = Some(font_family.into());
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.style.font_size = Some(font_size);
+        self
+    }
+
+    pub fn with_font_weight(mut self, font_weight: FontWeight) -> Self {
+        self.style.font_weight = Some(font_weight);
+        self
+    }
+
+    pub fn with_font_style(mut self, font_style: FontStyle) -> Self {
+        self.style.font_style = Some(font_style);
+        self
+    }
+
+    pub fn build(self) -> core::result::Result<Style, StyleError> {
+        self.style.validate()?;
+        Ok(self.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StyleBuilder, StyleError};
+    use crate::{PointType, Style};
+
+    #[test]
+    fn test_style_builder() {
+        assert_eq!(
+            StyleBuilder::new().with_stroke_opacity(1.5).build(),
+            Err(StyleError::OpacityOutOfRange("stroke-opacity", 1.5))
+        );
+        assert_eq!(
+            StyleBuilder::new().with_stroke_width(-2.0).build(),
+            Err(StyleError::NegativeStrokeWidth(-2.0))
+        );
+        assert_eq!(
+            StyleBuilder::new().with_text("A1").build(),
+            Err(StyleError::TextPathWithoutId)
+        );
+        assert!(StyleBuilder::new()
+            .with_text("A1")
+            .with_id("road")
+            .build()
+            .is_ok());
+        assert!(StyleBuilder::new()
+            .with_text("Paris")
+            .with_point_type(PointType::Text)
+            .build()
+            .is_ok());
+        assert_eq!(
+            StyleBuilder::new().with_point_type(PointType::Poi).build(),
+            Err(StyleError::PoiWithoutIcon)
+        );
+        assert_eq!(
+            StyleBuilder::from(Style::default()).build(),
+            Ok(Style::default())
+        );
+    }
+}
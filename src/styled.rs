@@ -0,0 +1,65 @@
+use crate::{Label, Style, ToSvgStr, ViewBox};
+use alloc::{string::String, vec::Vec};
+
+/// Geometry with its own style, which it is rendered with whatever the style of the svg it
+/// is part of, e.g. to attach styles to features when loading them.
+///
+/// ```
+/// # use geo_svg::{Color, Style, Styled, ToSvg};
+/// # use geo_types::Point;
+/// let red = Style {
+///     fill: Some(Color::Named("red")),
+///     ..Style::default()
+/// };
+/// let points = vec![
+///     Styled::new(Point::new(0.0, 0.0), red),
+///     Styled::new(Point::new(2.0, 0.0), Style::default()),
+/// ];
+/// assert!(points.to_svg().to_string().contains(r#"r="1" fill="red"/>"#));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Styled<G: ToSvgStr> {
+    pub geometry: G,
+    pub style: Style,
+}
+
+impl<G: ToSvgStr> Styled<G> {
+    pub fn new(geometry: G, style: Style) -> Self {
+        Self { geometry, style }
+    }
+}
+
+impl<G: ToSvgStr> ToSvgStr for Styled<G> {
+    fn to_svg_str(&self, _style: &Style) -> String {
+        self.geometry.to_svg_str(&self.style)
+    }
+
+    fn viewbox(&self, _style: &Style) -> ViewBox {
+        self.geometry.viewbox(&self.style)
+    }
+
+    fn labels(&self, _style: &Style) -> Vec<Label> {
+        self.geometry.labels(&self.style)
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.geometry.estimated_len()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.geometry.is_finite()
+    }
+
+    fn uses_xlink(&self, _style: &Style) -> bool {
+        self.geometry.uses_xlink(&self.style)
+    }
+
+    fn ids(&self, _style: &Style) -> Vec<String> {
+        self.geometry.ids(&self.style)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<crate::GeometryWarning> {
+        self.geometry.warnings()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::Style;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// Styles of named map layers, typically loaded from a configuration file.
+///
+/// With the `serde` feature the sheet (de)serializes as a map of layer names to styles, so it
+/// can be read from any serde format such as TOML, `from_json` covers JSON with the `json`
+/// feature. Fields left out of a layer take their default value.
+///
+/// ```
+/// # #[cfg(feature = "json")]
+/// # fn main() {
+/// use geo_svg::{StyleSheet, ToSvg};
+/// use geo_types::Point;
+///
+/// let sheet = StyleSheet::from_json(r##"{ "cities": { "fill": "#FF0000", "radius": 3.0 } }"##)
+///     .unwrap();
+/// let city = Point::new(1.0, 2.0);
+/// let svg = city.to_svg().with_style(sheet.get("cities").unwrap());
+/// assert!(svg.to_string().contains(r##"r="3" fill="#FF0000""##));
+/// # }
+/// # #[cfg(not(feature = "json"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct StyleSheet {
+    layers: BTreeMap<String, Style>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, layer: &str, style: Style) -> &mut Self {
+        self.layers.insert(layer.into(), style);
+        self
+    }
+
+    pub fn get(&self, layer: &str) -> Option<&Style> {
+        self.layers.get(layer)
+    }
+
+    /// Names of the styled layers, in alphabetical order.
+    pub fn layers(&self) -> impl Iterator<Item = &str> {
+        self.layers.keys().map(String::as_str)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::StyleSheet;
+    use crate::{Color, PointType};
+
+    #[test]
+    fn test_from_json() {
+        let sheet = StyleSheet::from_json(
+            r#"{
+                "roads": { "stroke_color": "rgb(200,0,100)", "stroke_width": 2.5 },
+                "pois": { "point_type": "Star", "fill": "gold" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(sheet.layers().collect::<Vec<_>>(), vec!["pois", "roads"]);
+        let roads = sheet.get("roads").unwrap();
+        assert_eq!(roads.stroke_color, Some(Color::Rgb(200, 0, 100)));
+        assert_eq!(roads.stroke_width, Some(2.5));
+        assert_eq!(roads.radius, 1.0);
+        let pois = sheet.get("pois").unwrap();
+        assert_eq!(pois.point_type, Some(PointType::Star));
+        assert_eq!(pois.fill, Some(Color::Named("gold")));
+        assert!(StyleSheet::from_json(r#"{ "roads": { "fill": "nope" } }"#).is_err());
+    }
+}
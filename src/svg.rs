@@ -1,6 +1,46 @@
-use crate::{Color, PointType, Style, ToSvgStr, Transform, ViewBox};
-use std::fmt::{Display, Formatter, Result};
+use crate::aspect::PreserveAspectRatio;
+use crate::debug::bbox_rect;
+use crate::defs::Defs;
+use crate::label::place_labels;
+use crate::layer::{InkscapeLayer, INKSCAPE_NAMESPACES};
+use crate::number::Bound;
+use crate::{
+    AspectAlign, Axes, Color, DominantBaseline, ElementMode, Filter, FontStyle, FontWeight,
+    Gradient, Inset, Label, LabelCollision, Layer, Legend, MeetOrSlice, NorthArrow, PathEncoding,
+    PointType, Projection, RadiusUnit, ScaleBar, Smoothing, Style, SvgProfile, SymbolRegistry,
+    TextAnchor, ToSvgStr, Transform, TransformFn, ViewBox,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result};
+use core::iter::FromIterator;
 
+/// Source of the luminance masking a document, see [`Svg::with_mask`] and
+/// [`Svg::with_gradient_mask`].
+#[derive(Clone)]
+pub enum Mask<'a> {
+    Svg(Box<Svg<'a>>),
+    /// Gradient over the bounding box of the masked content.
+    Gradient(Gradient),
+}
+
+impl<'a> Mask<'a> {
+    /// The document of the mask, if it's one.
+    pub(crate) fn svg(&self) -> Option<&Svg<'a>> {
+        match self {
+            Mask::Svg(svg) => Some(svg),
+            Mask::Gradient(_) => None,
+        }
+    }
+}
+
+/// A document being composed. Geometries are borrowed, not copied: combining and restyling
+/// svgs only clones the references and styles, whatever the size of the geometries.
 #[derive(Clone)]
 pub struct Svg<'a> {
     pub items: Vec<&'a dyn ToSvgStr>,
@@ -8,14 +48,73 @@ pub struct Svg<'a> {
     pub viewbox: ViewBox,
     pub style: Style,
     pub custom_viewbox: Option<ViewBox>,
+    /// The computed viewbox is expanded by this margin on every side.
+    pub margin: f64,
+    /// The computed viewbox is rounded outward to multiples of this step.
+    pub viewbox_step: Option<f64>,
+    pub label_collision: Option<LabelCollision>,
+    pub symbols: SymbolRegistry,
+    pub clip: Option<&'a dyn ToSvgStr>,
+    pub mask: Option<Mask<'a>>,
+    pub background: Option<Color>,
+    pub frame: Option<(Color, f32)>,
+    pub scale_bar: Option<ScaleBar>,
+    pub north_arrow: Option<NorthArrow>,
+    pub legend: Option<Legend>,
+    pub axes: Option<Axes>,
+    /// Nested maps and the area of this document they're drawn in.
+    pub insets: Vec<Inset<'a>>,
+    pub layer: Option<Layer>,
+    /// Layers are also marked as Inkscape layers.
+    pub inkscape_layers: bool,
+    /// The content is clipped to the document viewbox.
+    pub clip_to_viewbox: bool,
+    /// Width, height and padding of a fixed canvas the geometries are centered in.
+    pub canvas: Option<(f64, f64, f64)>,
+    /// How the viewbox is fitted into a viewport with a different aspect ratio.
+    pub preserve_aspect_ratio: (AspectAlign, MeetOrSlice),
 }
 
 impl<'a> Svg<'a> {
+    pub(crate) fn new(items: Vec<&'a dyn ToSvgStr>) -> Self {
+        Svg {
+            items,
+            siblings: vec![],
+            viewbox: ViewBox::default(),
+            custom_viewbox: None,
+            margin: 0.0,
+            viewbox_step: None,
+            label_collision: None,
+            symbols: SymbolRegistry::new(),
+            clip: None,
+            mask: None,
+            background: None,
+            frame: None,
+            scale_bar: None,
+            north_arrow: None,
+            legend: None,
+            axes: None,
+            insets: vec![],
+            layer: None,
+            inkscape_layers: false,
+            clip_to_viewbox: false,
+            canvas: None,
+            preserve_aspect_ratio: (AspectAlign::XMidYMid, MeetOrSlice::Meet),
+            style: Style::default(),
+        }
+    }
+
     pub fn and(mut self, sibling: Svg<'a>) -> Self {
         self.siblings.push(sibling);
         self
     }
 
+    /// Adds all the svgs as siblings at once, see also the [`FromIterator`] implementation.
+    pub fn and_all(mut self, siblings: impl IntoIterator<Item = Svg<'a>>) -> Self {
+        self.siblings.extend(siblings);
+        self
+    }
+
     pub fn with_style(mut self, style: &Style) -> Self {
         self.style = style.clone();
         for sibling in &mut self.siblings {
@@ -24,8 +123,10 @@ impl<'a> Svg<'a> {
         self
     }
 
-    pub fn with_margin(mut self, margin: f32) -> Self {
-        self.viewbox = self.viewbox.with_margin(margin);
+    /// Leaves `margin` around the geometries, before the viewbox is rounded. A custom
+    /// viewbox is kept as it is.
+    pub fn with_margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
         self
     }
 
@@ -53,23 +154,248 @@ impl<'a> Svg<'a> {
         text_classes: Option<String>,
     ) -> Self {
         self.style.text = text.clone();
-        self.style.text_start_offset = start_offset.clone();
+        self.style.text_start_offset = start_offset;
         self.style.text_classes = text_classes.clone();
         self
     }
 
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.style.label = Some(label.into());
+        self
+    }
+
+    pub fn with_label_priority(mut self, priority: i32) -> Self {
+        self.style.label_priority = priority;
+        self
+    }
+
+    /// Fills the whole viewbox with `color` behind the geometries.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Draws a neatline of the given color and width just inside the edges of the viewbox.
+    pub fn with_frame(mut self, stroke: Color, width: f32) -> Self {
+        self.frame = Some((stroke, width));
+        self
+    }
+
+    /// Adds a scale bar sized and placed according to the final viewbox.
+    pub fn with_scale_bar(mut self, scale_bar: ScaleBar) -> Self {
+        self.scale_bar = Some(scale_bar);
+        self
+    }
+
+    /// Adds a north arrow placed according to the final viewbox.
+    pub fn with_north_arrow(mut self, north_arrow: NorthArrow) -> Self {
+        self.north_arrow = Some(north_arrow);
+        self
+    }
+
+    /// Adds a legend placed according to the final viewbox.
+    pub fn with_legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    /// Embeds `inset` as a nested map with its own viewbox, fitted in the `width` by `height`
+    /// area at `x`, `y` in the coordinates of this document, e.g. an overview map.
+    pub fn inset(mut self, inset: Svg<'a>, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.insets.push(Inset::new(inset, x, y, width, height));
+        self
+    }
+
+    /// Draws axes with ticks along the left and bottom edges of the final viewbox.
+    pub fn with_axes(mut self, axes: Axes) -> Self {
+        self.axes = Some(axes);
+        self
+    }
+
+    pub fn with_label_collision(mut self, collision: LabelCollision) -> Self {
+        self.label_collision = Some(collision);
+        self
+    }
+
+    pub fn with_text_halo(mut self, color: Color, width: f32) -> Self {
+        self.style.text_halo = Some((color, width));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_text_halo(color, width);
+        }
+        self
+    }
+
+    pub fn with_font_family(mut self, font_family: &str) -> Self {
+        self.style.font_family = Some(font_family.into());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_font_family(font_family);
+        }
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.style.font_size = Some(font_size);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_font_size(font_size);
+        }
+        self
+    }
+
+    pub fn with_font_weight(mut self, font_weight: FontWeight) -> Self {
+        self.style.font_weight = Some(font_weight);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_font_weight(font_weight);
+        }
+        self
+    }
+
+    pub fn with_font_style(mut self, font_style: FontStyle) -> Self {
+        self.style.font_style = Some(font_style);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_font_style(font_style);
+        }
+        self
+    }
+
+    pub fn with_text_anchor(mut self, text_anchor: TextAnchor) -> Self {
+        self.style.text_anchor = Some(text_anchor);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_text_anchor(text_anchor);
+        }
+        self
+    }
+
+    pub fn with_dominant_baseline(mut self, dominant_baseline: DominantBaseline) -> Self {
+        self.style.dominant_baseline = Some(dominant_baseline);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_dominant_baseline(dominant_baseline);
+        }
+        self
+    }
+
+    pub fn with_line_height(mut self, line_height: f32) -> Self {
+        self.style.line_height = Some(line_height);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_line_height(line_height);
+        }
+        self
+    }
+
     pub fn with_transform(mut self, transform: Transform) -> Self {
         self.style.transform = Some(transform);
         self
     }
 
-    pub fn with_icon_svg_path(mut self, path: &str, view_box: (i32, i32, i32, i32), width_height: (i32, i32)) -> Self {
+    /// Adds a function to the `transform` attribute, after the ones already there.
+    fn with_transform_fn(mut self, transform_function: TransformFn) -> Self {
+        let transform = self
+            .style
+            .transform
+            .take()
+            .unwrap_or_else(|| Transform::new(vec![]));
+        self.style.transform = Some(transform.then(transform_function));
+        self
+    }
+
+    /// Moves the geometries by `dx`, `dy` when drawing them, leaving their coordinates as they
+    /// are, e.g. to nudge them or explode a view.
+    pub fn with_translate(self, dx: f64, dy: f64) -> Self {
+        self.with_transform_fn(TransformFn::Translate(dx, Some(dy)))
+    }
+
+    /// Scales the geometries by `scale` around the origin when drawing them.
+    pub fn with_scale(self, scale: f64) -> Self {
+        self.with_transform_fn(TransformFn::Scale(scale, None))
+    }
+
+    /// Rotates the geometries by `degrees` around `cx`, `cy` when drawing them.
+    pub fn with_rotate(self, degrees: f64, cx: f64, cy: f64) -> Self {
+        self.with_transform_fn(TransformFn::Rotate(degrees, Some((cx, cy))))
+    }
+
+    /// Draws points as an icon made of the `path` svg markup, which is written as is: unlike
+    /// texts, ids and classes it isn't escaped, so it must come from a trusted source.
+    pub fn with_icon_svg_path(
+        mut self,
+        path: &str,
+        view_box: (i32, i32, i32, i32),
+        width_height: (i32, i32),
+    ) -> Self {
         self.style.icon_svg_path = Some(path.into());
         self.style.icon_svg_viewbox = Some(view_box);
         self.style.icon_svg_width_height = Some(width_height);
         self
     }
 
+    pub fn with_symbol(mut self, name: &str) -> Self {
+        self.style.point_type = Some(PointType::Symbol);
+        self.style.symbol = Some(name.into());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_symbol(name);
+        }
+        self
+    }
+
+    pub fn with_icon_href(mut self, href: &str) -> Self {
+        self.style.point_type = Some(PointType::Symbol);
+        self.style.icon_href = Some(href.into());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_icon_href(href);
+        }
+        self
+    }
+
+    pub fn with_symbols(mut self, symbols: SymbolRegistry) -> Self {
+        self.symbols.extend(&symbols);
+        self
+    }
+
+    /// Groups this svg and its siblings into a layer.
+    pub fn with_layer(mut self, layer: Layer) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Marks the layers of this svg and its siblings as Inkscape layers, which can then be
+    /// locked and hidden from the Inkscape layers panel.
+    pub fn with_inkscape_layers(mut self) -> Self {
+        self.inkscape_layers = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_inkscape_layers();
+        }
+        self
+    }
+
+    /// Writes the svg for renderers of the given version of the specification, see
+    /// [`SvgProfile`].
+    pub fn with_profile(mut self, profile: SvgProfile) -> Self {
+        self.style.profile = profile;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_profile(profile);
+        }
+        self
+    }
+
+    /// Clips this svg and its siblings to the shape of `clip`.
+    pub fn with_clip(mut self, clip: &'a dyn ToSvgStr) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
+    /// Masks this svg and its siblings with the luminance of `mask`: white areas are fully
+    /// shown and black ones hidden, so a semi-transparent or blurred mask gives soft edges.
+    pub fn with_mask(mut self, mask: Svg<'a>) -> Self {
+        self.mask = Some(Mask::Svg(Box::new(mask)));
+        self
+    }
+
+    /// Masks this svg and its siblings with the luminance of `gradient`, laid over their
+    /// bounding box, e.g. to fade the map out towards its edges.
+    pub fn with_gradient_mask(mut self, gradient: Gradient) -> Self {
+        self.mask = Some(Mask::Gradient(gradient));
+        self
+    }
+
     pub fn with_point_type(mut self, point_type: Option<PointType>) -> Self {
         self.style.point_type = point_type;
         self
@@ -139,17 +465,348 @@ impl<'a> Svg<'a> {
         self
     }
 
-    pub fn with_custom_viewbox(mut self, min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
+    pub fn with_radius_unit(mut self, radius_unit: RadiusUnit) -> Self {
+        self.style.radius_unit = radius_unit;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_radius_unit(radius_unit);
+        }
+        self
+    }
+
+    /// Simplifies line strings and polygons with the Ramer–Douglas–Peucker algorithm before
+    /// rendering them, dropping vertices closer than `epsilon` to the simplified outline.
+    #[cfg(feature = "geo")]
+    pub fn with_simplify_epsilon(mut self, epsilon: f64) -> Self {
+        self.style.simplify_epsilon = Some(epsilon);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_simplify_epsilon(epsilon);
+        }
+        self
+    }
+
+    /// Rounds the coordinates of line strings and polygons to multiples of `step` once they're
+    /// projected, dropping the vertices which then fall on the previous one, to shrink the
+    /// output when the full precision isn't visible anyway.
+    pub fn with_snap_grid(mut self, step: f64) -> Self {
+        self.style.snap_grid = Some(step);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_snap_grid(step);
+        }
+        self
+    }
+
+    /// Marks every vertex of the line strings and polygons with a dot and its index in the
+    /// ring, to see the winding, duplicate points and self-intersections.
+    pub fn with_debug_vertices(mut self) -> Self {
+        self.style.debug_vertices = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_debug_vertices();
+        }
+        self
+    }
+
+    /// Outlines the extent of every geometry, as computed for the viewbox of the document and
+    /// including the radius of points, with a dashed rectangle.
+    pub fn with_debug_bbox(mut self) -> Self {
+        self.style.debug_bbox = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_debug_bbox();
+        }
+        self
+    }
+
+    /// Draws a point styled with `style` on the centroid of every line string and polygon, e.g.
+    /// to compare it with the label positions.
+    pub fn with_centroid_markers(mut self, style: &Style) -> Self {
+        self.style.centroid_marker = Some(Box::new(style.clone()));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_centroid_markers(style);
+        }
+        self
+    }
+
+    /// Draws arrow heads every `spacing` along the line strings, pointing in their direction,
+    /// e.g. for one-way streets or flows. They are sized by the radius and painted with the
+    /// stroke color.
+    pub fn with_direction_arrows(mut self, spacing: f64) -> Self {
+        self.style.direction_arrows = Some(spacing);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_direction_arrows(spacing);
+        }
+        self
+    }
+
+    /// Writes the length of every segment of the lines and line strings along its middle, in
+    /// map units with `precision` decimals and followed by `unit`.
+    pub fn with_segment_lengths(mut self, unit: &str, precision: usize) -> Self {
+        self.style.segment_lengths = Some((unit.into(), precision));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_segment_lengths(unit, precision);
+        }
+        self
+    }
+
+    /// Labels the polygons with their area in square map units, written in place of `{area}` in
+    /// `format`, or with `N` decimals in place of `{area:.N}`, e.g. `"{area:.1} m²"`. The area
+    /// goes under the label of polygons that have one.
+    pub fn with_area_label(mut self, format: &str) -> Self {
+        self.style.area_label = Some(format.into());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_area_label(format);
+        }
+        self
+    }
+
+    /// Labels polygons with lon/lat coordinates with their area on the WGS84 ellipsoid in
+    /// square meters, see [`Svg::with_area_label`].
+    #[cfg(feature = "geo")]
+    pub fn with_geodesic_area_label(mut self, format: &str) -> Self {
+        self.style.area_label = Some(format.into());
+        self.style.geodesic_area = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_geodesic_area_label(format);
+        }
+        self
+    }
+
+    /// Writes the coordinates of every vertex of the line strings and polygons next to it, with
+    /// `precision` decimals. These are the coordinates as drawn, i.e. once projected.
+    pub fn with_coordinate_labels(mut self, precision: usize) -> Self {
+        self.style.coordinate_labels = Some(precision);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_coordinate_labels(precision);
+        }
+        self
+    }
+
+    /// Writes the exterior rings of polygons counter-clockwise and their holes clockwise, with
+    /// the y axis pointing up, for tools relying on the winding or the `nonzero` fill rule.
+    /// The geometries themselves are left untouched.
+    pub fn with_normalized_winding(mut self) -> Self {
+        self.style.normalize_winding = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_normalized_winding();
+        }
+        self
+    }
+
+    /// Outlines the holes of polygons with their own style, e.g. dashed, instead of the stroke
+    /// of the polygon, which then only outlines the exterior. Holes aren't filled unless `style`
+    /// has a fill.
+    pub fn with_hole_style(mut self, style: &Style) -> Self {
+        self.style.hole_style = Some(Box::new(style.clone()));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_hole_style(style);
+        }
+        self
+    }
+
+    pub fn with_smoothing(mut self, smoothing: Smoothing) -> Self {
+        self.style.smoothing = Some(smoothing);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_smoothing(smoothing);
+        }
+        self
+    }
+
+    pub fn with_element_mode(mut self, element_mode: ElementMode) -> Self {
+        self.style.element_mode = element_mode;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_element_mode(element_mode);
+        }
+        self
+    }
+
+    pub fn with_path_encoding(mut self, path_encoding: PathEncoding) -> Self {
+        self.style.path_encoding = path_encoding;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_path_encoding(path_encoding);
+        }
+        self
+    }
+
+    /// Projects the coordinates of points, lines, line strings, polygons and rectangles, which
+    /// are then longitudes and latitudes in degrees, before rendering them.
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.style.projection = Some(projection.clone());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_projection(projection.clone());
+        }
+        self
+    }
+
+    /// Draws lines and line strings, whose coordinates are then longitudes and latitudes in
+    /// degrees, along great circles split every `step` degrees of arc. It happens before the
+    /// projection, so the curves follow the projected great circles.
+    pub fn with_great_circles(mut self, step: f64) -> Self {
+        self.style.great_circle_step = Some(step);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_great_circles(step);
+        }
+        self
+    }
+
+    /// Exchanges x and y before rendering, for coordinates stored latitude first. It happens
+    /// before the geographic options and the projection, which then get longitudes first.
+    pub fn with_swap_axes(mut self) -> Self {
+        self.style.swap_axes = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_swap_axes();
+        }
+        self
+    }
+
+    /// Splits lines and line strings, whose coordinates are then longitudes and latitudes in
+    /// degrees, where they cross the antimeridian, and shifts the longitudes of polygons
+    /// crossing it so that they're drawn in one piece past ±180°.
+    pub fn with_wrap_antimeridian(mut self) -> Self {
+        self.style.wrap_antimeridian = true;
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_wrap_antimeridian();
+        }
+        self
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.style.filter = Some(filter);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_filter(filter);
+        }
+        self
+    }
+
+    pub fn with_corner_radius(mut self, corner_radius: f32) -> Self {
+        self.style.corner_radius = Some(corner_radius);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_corner_radius(corner_radius);
+        }
+        self
+    }
+
+    pub fn with_custom_viewbox(mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
         self.custom_viewbox = Some(ViewBox::new(min_x, min_y, max_x, max_y));
         self
     }
 
+    /// Renders the document on a `width` by `height` canvas, with the geometries centered and
+    /// at least `padding` from its edges, see [`ViewBox::fit_to_canvas`]. The background, if
+    /// any, also fills the space left on either side.
+    pub fn with_canvas(mut self, width: f64, height: f64, padding: f64) -> Self {
+        self.canvas = Some((width, height, padding));
+        self
+    }
+
+    /// Sets how the document is scaled inside a container with a different aspect ratio, e.g.
+    /// `(AspectAlign::XMidYMid, MeetOrSlice::Slice)` to fill it and cut off the overflow.
+    pub fn with_preserve_aspect_ratio(
+        mut self,
+        align: AspectAlign,
+        meet_or_slice: MeetOrSlice,
+    ) -> Self {
+        self.preserve_aspect_ratio = (align, meet_or_slice);
+        self
+    }
+
+    /// Frames the document on `focus`, with `padding` around its styled extent, while still
+    /// drawing the other geometries clipped to that frame, e.g. a parcel and its neighbours.
+    /// The extent is computed with the current style of this svg.
+    pub fn with_focus<G: ToSvgStr + ?Sized>(mut self, focus: &G, padding: f64) -> Self {
+        self.custom_viewbox = Some(ViewBox::of(focus, &self.style).expand_by(padding, padding));
+        self.clip_to_viewbox = true;
+        self
+    }
+
+    /// Expands the computed viewbox outward to multiples of `step`, e.g. 10 m or 0.01°, see
+    /// [`ViewBox::rounded`]. A custom viewbox is left as it is.
+    pub fn with_rounded_viewbox(mut self, step: f64) -> Self {
+        self.viewbox_step = Some(step);
+        self
+    }
+
     pub fn svg_str(&self) -> String {
-        self.items
+        self.render(&mut Defs::default(), None)
+    }
+
+    /// Renders the items and siblings, adding what they reference to `defs`. When `labels` is
+    /// given, labels are collected there instead of being rendered.
+    fn render(&self, defs: &mut Defs, mut labels: Option<&mut Vec<Label>>) -> String {
+        let style = match labels {
+            Some(_) => Style {
+                label: None,
+                ..self.style.clone()
+            },
+            None => self.style.clone(),
+        };
+        let mut content = String::with_capacity(self.estimated_len());
+        for item in &self.items {
+            if let Some(labels) = labels.as_deref_mut() {
+                labels.extend(item.labels(&self.style));
+            }
+            content.push_str(&item.to_svg_str(&style));
+            if style.debug_bbox {
+                content.push_str(&bbox_rect(&item.viewbox(&self.style)));
+            }
+        }
+        if let (Some(filter), false) = (self.style.filter, content.is_empty()) {
+            let id = defs.add_filter(filter);
+            content = format!(r#"<g filter="url(#{})">{}</g>"#, id, content);
+        }
+        for sibling in &self.siblings {
+            content.push_str(&sibling.render(defs, labels.as_deref_mut()));
+        }
+        let mut group = String::new();
+        if let Some(clip) = self.clip {
+            let id = defs.add_clip_path(clip.to_svg_str(&Style::default()));
+            group.push_str(&format!(r#" clip-path="url(#{})""#, id));
+        }
+        if let Some(mask) = &self.mask {
+            let id = match mask {
+                Mask::Svg(mask) => {
+                    let mask_content = mask.render(defs, None);
+                    defs.add_mask(mask_content)
+                }
+                Mask::Gradient(gradient) => defs.add_gradient_mask(gradient.clone()),
+            };
+            group.push_str(&format!(r#" mask="url(#{})""#, id));
+        }
+        let content = if group.is_empty() {
+            content
+        } else {
+            format!("<g{}>{}</g>", group, content)
+        };
+        match &self.layer {
+            Some(layer) if self.inkscape_layers => {
+                format!("<g{}>{}</g>", InkscapeLayer(layer), content)
+            }
+            Some(layer) => format!("<g{}>{}</g>", layer, content),
+            None => content,
+        }
+    }
+
+    fn content(&self, defs: &mut Defs) -> String {
+        match self.label_collision {
+            Some(collision) => {
+                let mut labels = Vec::new();
+                let content = self.render(defs, Some(&mut labels));
+                place_labels(labels, collision)
+                    .iter()
+                    .fold(content, |mut content, label| {
+                        content.push_str(&label.to_string());
+                        content
+                    })
+            }
+            None => self.render(defs, None),
+        }
+    }
+
+    fn all_symbols(&self) -> SymbolRegistry {
+        self.siblings
             .iter()
-            .map(|item| item.to_svg_str(&self.style))
-            .chain(self.siblings.iter().map(Svg::svg_str))
-            .collect()
+            .chain(self.insets.iter().map(|inset| &inset.svg))
+            .fold(self.symbols.clone(), |mut symbols, sibling| {
+                symbols.extend(&sibling.all_symbols());
+                symbols
+            })
     }
 
     pub fn viewbox(&self) -> ViewBox {
@@ -163,22 +820,210 @@ impl<'a> Svg<'a> {
     }
 }
 
+impl<'a> Svg<'a> {
+    /// Namespaces of the prefixed attributes written by the items, siblings, masks and insets,
+    /// found from their styles.
+    pub(crate) fn namespaces(&self) -> Namespaces {
+        let own = Namespaces {
+            xlink: self.items.iter().any(|item| item.uses_xlink(&self.style)),
+            inkscape: self.inkscape_layers && self.layer.is_some(),
+        };
+        self.siblings
+            .iter()
+            .chain(self.mask.iter().filter_map(Mask::svg))
+            .chain(self.insets.iter().map(|inset| &inset.svg))
+            .map(Svg::namespaces)
+            .fold(own, Namespaces::union)
+    }
+
+    pub(crate) fn document_viewbox(&self) -> ViewBox {
+        let viewbox = self.custom_viewbox.unwrap_or_else(|| {
+            let viewbox = self.viewbox().with_margin(self.margin);
+            match self.viewbox_step {
+                Some(step) => viewbox.rounded(step),
+                None => viewbox,
+            }
+        });
+        match self.canvas {
+            Some((width, height, padding)) => viewbox.fit_to_canvas(width, height, padding),
+            None => viewbox,
+        }
+    }
+
+    /// Everything inside the `<svg>` element of the document.
+    pub(crate) fn document_body(&self) -> String {
+        let mut defs = Defs::new(self.all_symbols());
+        let body = self.body(&mut defs);
+        format!("{}{}", defs, body)
+    }
+
+    /// The document without its definitions, which are added to `defs` so that insets don't
+    /// define conflicting ids.
+    pub(crate) fn body(&self, defs: &mut Defs) -> String {
+        let viewbox = self.document_viewbox();
+        let content = self.content(defs);
+        let content = if self.clip_to_viewbox {
+            let id = defs.add_clip_path(extent_rect(&viewbox, ""));
+            format!(r#"<g clip-path="url(#{})">{}</g>"#, id, content)
+        } else {
+            content
+        };
+        let background = self
+            .background
+            .map(|color| extent_rect(&viewbox, &format!(r#" fill="{}""#, color)))
+            .unwrap_or_default();
+        let scale_bar = self
+            .scale_bar
+            .as_ref()
+            .map(|scale_bar| scale_bar.to_svg_str(&viewbox))
+            .unwrap_or_default();
+        let north_arrow = self
+            .north_arrow
+            .as_ref()
+            .map(|north_arrow| north_arrow.to_svg_str(&viewbox))
+            .unwrap_or_default();
+        let legend = self
+            .legend
+            .as_ref()
+            .map(|legend| legend.to_svg_str(&viewbox))
+            .unwrap_or_default();
+        let axes = self
+            .axes
+            .as_ref()
+            .map(|axes| axes.to_svg_str(&viewbox))
+            .unwrap_or_default();
+        let insets: String = self
+            .insets
+            .iter()
+            .map(|inset| inset.to_svg_str(defs))
+            .collect();
+        let frame = self
+            .frame
+            .map(|(color, width)| {
+                // inset by half the stroke so that it isn't cut by the edges
+                extent_rect(
+                    &viewbox.with_margin(-f64::from(width) / 2.0),
+                    &format!(
+                        r#" fill="none" stroke="{}" stroke-width="{}""#,
+                        color, width
+                    ),
+                )
+            })
+            .unwrap_or_default();
+        format!(
+            "{background}{content}{axes}{insets}{scale_bar}{north_arrow}{legend}{frame}",
+            background = background,
+            content = content,
+            axes = axes,
+            insets = insets,
+            scale_bar = scale_bar,
+            north_arrow = north_arrow,
+            legend = legend,
+            frame = frame,
+        )
+    }
+}
+
+/// Collects svgs as the siblings of an empty svg, which keeps them side by side instead of
+/// nesting them like chained [`Svg::and`] calls on the last one would.
+impl<'a> FromIterator<Svg<'a>> for Svg<'a> {
+    fn from_iter<I: IntoIterator<Item = Svg<'a>>>(svgs: I) -> Self {
+        Svg::new(vec![]).and_all(svgs)
+    }
+}
+
+impl<'a> Extend<Svg<'a>> for Svg<'a> {
+    fn extend<I: IntoIterator<Item = Svg<'a>>>(&mut self, svgs: I) {
+        self.siblings.extend(svgs);
+    }
+}
+
 impl<'a> Display for Svg<'a> {
     fn fmt(&self, fmt: &mut Formatter) -> Result {
-        let viewbox = self.custom_viewbox.unwrap_or(self.viewbox());
-        write!(
-            fmt,
-            r#"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="{x} {y} {w} {h}">{content}</svg>"#,
-            x = viewbox.min_x(),
-            y = viewbox.min_y(),
-            w = viewbox.width(),
-            h = viewbox.height(),
-            content = self
-                .items
-                .iter()
-                .map(|item| item.to_svg_str(&self.style))
-                .chain(self.siblings.iter().map(Svg::svg_str))
-                .collect::<String>()
-        )
+        fmt.write_str(&self.document(None, ""))
+    }
+}
+
+impl Svg<'_> {
+    /// The standalone document, whose `<svg>` element gets `attributes` and is `size` wide and
+    /// high, or the size of the canvas if there's none.
+    pub(crate) fn document(&self, size: Option<(f64, f64)>, attributes: &str) -> String {
+        let body = self.document_body();
+        let mut root_attributes = String::from(attributes);
+        if let Some((width, height)) =
+            size.or(self.canvas.map(|(width, height, _)| (width, height)))
+        {
+            root_attributes.push_str(&format!(r#" width="{}" height="{}""#, width, height));
+        }
+        let (align, meet_or_slice) = self.preserve_aspect_ratio;
+        let start = document_start(
+            &self.document_viewbox(),
+            PreserveAspectRatio(align, meet_or_slice),
+            self.namespaces(),
+            &root_attributes,
+        );
+        format!("{}{}</svg>", start, body)
+    }
+}
+
+/// Namespaces declared on the `<svg>` element besides the svg one, for the prefixed
+/// attributes of the document, such as the `xlink:href` of the svg 1.1 profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Namespaces {
+    pub xlink: bool,
+    pub inkscape: bool,
+}
+
+impl Namespaces {
+    pub fn union(self, other: Namespaces) -> Namespaces {
+        Namespaces {
+            xlink: self.xlink || other.xlink,
+            inkscape: self.inkscape || other.inkscape,
+        }
     }
 }
+
+impl Display for Namespaces {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        if self.inkscape {
+            fmt.write_str(INKSCAPE_NAMESPACES)?;
+        }
+        if self.xlink {
+            fmt.write_str(XLINK_NAMESPACE)?;
+        }
+        Ok(())
+    }
+}
+
+const XLINK_NAMESPACE: &str = r#" xmlns:xlink="http://www.w3.org/1999/xlink""#;
+
+/// Opening tag of a standalone document showing `viewbox`, with `attributes` first, such as
+/// its size.
+pub(crate) fn document_start(
+    viewbox: &ViewBox,
+    preserve_aspect_ratio: PreserveAspectRatio,
+    namespaces: Namespaces,
+    attributes: &str,
+) -> String {
+    format!(
+        r#"<svg{attributes}{namespaces} xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="{preserve_aspect_ratio}" viewBox="{x} {y} {w} {h}">"#,
+        attributes = attributes,
+        namespaces = namespaces,
+        preserve_aspect_ratio = preserve_aspect_ratio,
+        x = Bound(viewbox.min_x()),
+        y = Bound(viewbox.min_y()),
+        w = Bound(viewbox.width()),
+        h = Bound(viewbox.height()),
+    )
+}
+
+pub(crate) fn extent_rect(viewbox: &ViewBox, attributes: &str) -> String {
+    format!(
+        r#"<rect x="{x}" y="{y}" width="{w}" height="{h}"{attributes}/>"#,
+        x = Bound(viewbox.min_x()),
+        y = Bound(viewbox.min_y()),
+        w = Bound(viewbox.width()),
+        h = Bound(viewbox.height()),
+        attributes = attributes,
+    )
+}
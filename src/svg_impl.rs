@@ -1,11 +1,47 @@
-use crate::{PointType, Style, ToSvgStr, ViewBox};
+use crate::antimeridian::{split_line_string, unwrap_polygon};
+use crate::arrows::direction_arrows;
+use crate::debug::{
+    centroid_marker, coordinate_labels, line_string_centroid, polygon_centroid, vertex_markers,
+};
+use crate::escape::Escaped;
+use crate::great_circle::densify;
+use crate::label::{representative_point, text_content, unsigned_area};
+use crate::marker::{pin_path, pixel_marker, symbol_path};
+#[cfg(feature = "geo")]
+use crate::measure::geodesic_area;
+use crate::measure::{area_label_style, segment_lengths};
+use crate::number::Num;
+use crate::path::PathBuilder;
+use crate::projection::{projected, swapped};
+#[cfg(feature = "geo")]
+use crate::simplify::{simplify_line_string, simplify_polygon};
+use crate::smooth::smooth_path;
+use crate::snap::{snap_line_string, snap_polygon};
+use crate::style::{with_halo_copy, TextStyle};
+use crate::symbol::symbol_id;
+use crate::to_svg_str::{COORD_LEN, ELEMENT_LEN};
+#[cfg(feature = "validation")]
+use crate::validation::{line_string_warnings, polygon_warnings};
+use crate::winding::normalize_winding;
+#[cfg(feature = "validation")]
+use crate::GeometryWarning;
+use crate::{
+    DominantBaseline, ElementMode, Label, PointType, RadiusUnit, Style, TextAnchor, ToSvgStr,
+    ViewBox,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use geo_types::{
-    CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use num_traits::NumCast;
 
-impl<T: CoordNum> ToSvgStr for Coordinate<T> {
+impl<T: CoordNum> ToSvgStr for Coord<T> {
     fn to_svg_str(&self, style: &Style) -> String {
         Point::from(*self).to_svg_str(style)
     }
@@ -13,94 +49,258 @@ impl<T: CoordNum> ToSvgStr for Coordinate<T> {
     fn viewbox(&self, style: &Style) -> ViewBox {
         Point::from(*self).viewbox(style)
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        Point::from(*self).labels(style)
+    }
+
+    fn is_finite(&self) -> bool {
+        let finite = |value: T| NumCast::from(value).is_some_and(f64::is_finite);
+        finite(self.x) && finite(self.y)
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for Point<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        if let Some(point_type) = style.point_type.clone() {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.to_svg_str(&style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.to_svg_str(&style);
+        }
+        let pixel_cap = match (style.radius_unit, &style.point_type) {
+            (RadiusUnit::Pixels, None | Some(PointType::Circle)) => Some("round"),
+            (RadiusUnit::Pixels, Some(PointType::Square)) => Some("square"),
+            _ => None,
+        };
+        let marker = if let Some(cap) = pixel_cap {
+            pixel_marker(
+                NumCast::from(self.x()).unwrap_or(0.0),
+                NumCast::from(self.y()).unwrap_or(0.0),
+                cap,
+                style,
+            )
+        } else if let Some(point_type) = style.point_type.clone() {
             match point_type {
-            PointType::Text => format!(
-                r#"<text class="{}" x="{x:?}" y="{y:?}" {style}>{text}</text>"#,
-                class = style.text_classes.clone().unwrap_or("".into()),
-                x = self.x(),
-                y = self.y(),
-                text = style.text.clone().unwrap_or("".into()),
-                style = style,
-            ),
-            PointType::Poi => {
-                let (min_x, min_y, vb_width, vb_height) = style.icon_svg_viewbox.unwrap_or((0,0,100,100));
-                let (width, height) = style.icon_svg_width_height.unwrap_or((60,60));
-                let (x, y) = (format!("{:?}", self.x()).parse::<f64>().unwrap_or(0.0),
-                format!("{:?}", self.y()).parse::<f64>().unwrap_or(0.0));
-
-                #[allow(unused_assignments, unused_mut)]
-                let mut dbg_cir = "".to_string();
-
-                // dbg_cir = format!(r#"<circle cx="{x:?}" cy="{y:?}" r=10></circle>"#,
-                //     x = x,
-                //     y = y
-                // );
-
-                let text = style.text.clone().and_then(|text|
-                    Some(
-                        format!(r#"<text x="{x:?}" y="{y:?}">{text}</text>{debug_circle}"#,
-                            debug_circle = dbg_cir,
-                            x = (x + width as f64 / 2.0 + 15.0),
-                            y = (y + height as f64 - 45.0),
-                            text = text,
-                        )
+                PointType::Text => with_halo_copy(style, |style| {
+                    format!(
+                        r#"<text class="{class}" x="{x}" y="{y}" {style}{text_style}>{text}</text>"#,
+                        class = Escaped(style.text_classes.as_deref().unwrap_or("")),
+                        x = Num(self.x()),
+                        y = Num(self.y()),
+                        text = text_content(style.text.as_deref().unwrap_or(""), self.x(), style),
+                        // the halo takes over the stroke of the text
+                        style = match style.text_halo {
+                            Some(_) => Style {
+                                stroke_color: None,
+                                stroke_width: None,
+                                stroke_opacity: None,
+                                ..style.clone()
+                            },
+                            None => style.clone(),
+                        },
+                        text_style = TextStyle(style),
                     )
-                ).unwrap_or("".into());
+                }),
+                PointType::Glyph(glyph) => format!(
+                    r#"<text x="{x}" y="{y}" text-anchor="middle" dominant-baseline="central" font-size="{size}"{style}>{glyph}</text>"#,
+                    x = Num(self.x()),
+                    y = Num(self.y()),
+                    size = style.radius * 2.0,
+                    style = style,
+                    glyph = Escaped(glyph.encode_utf8(&mut [0; 4])),
+                ),
+                PointType::Poi => {
+                    let (min_x, min_y, vb_width, vb_height) =
+                        style.icon_svg_viewbox.unwrap_or((0, 0, 100, 100));
+                    let (width, height) = style.icon_svg_width_height.unwrap_or((60, 60));
+                    let (x, y): (f64, f64) = (
+                        NumCast::from(self.x()).unwrap_or(0.0),
+                        NumCast::from(self.y()).unwrap_or(0.0),
+                    );
 
-                format!(
-                    r#"<svg x="{x:?}" y="{y:?}" width="{w}" height="{h}" viewBox="{mx} {my} {vbw} {vbh}" {style}>{path}</svg>{text}"#,
+                    #[allow(unused_assignments, unused_mut)]
+                    let mut dbg_cir = "".to_string();
+
+                    // dbg_cir = format!(r#"<circle cx="{x:?}" cy="{y:?}" r=10></circle>"#,
+                    //     x = x,
+                    //     y = y
+                    // );
+
+                    let text = style
+                        .text
+                        .clone()
+                        .map(|text| {
+                            format!(
+                                r#"<text x="{x}" y="{y}"{text_style}>{text}</text>{debug_circle}"#,
+                                text_style = TextStyle(style),
+                                debug_circle = dbg_cir,
+                                x = Num(x + width as f64 / 2.0 + 15.0),
+                                y = Num(y + height as f64 - 45.0),
+                                text = text_content(&text, x + width as f64 / 2.0 + 15.0, style),
+                            )
+                        })
+                        .unwrap_or("".into());
+
+                    format!(
+                        r#"<svg x="{x}" y="{y}" width="{w}" height="{h}" viewBox="{mx} {my} {vbw} {vbh}" {style}>{path}</svg>{text}"#,
+                        style = style,
+                        path = style.icon_svg_path.clone().unwrap_or("".into()),
+                        w = width,
+                        h = height,
+                        mx = min_x,
+                        my = min_y,
+                        vbw = vb_width,
+                        vbh = vb_height,
+                        x = Num(x - (width as f64 / 2.0)),
+                        y = Num(y - (height as f64 / 2.0)),
+                        text = text,
+                    )
+                }
+                PointType::Square
+                | PointType::Triangle
+                | PointType::Diamond
+                | PointType::Star
+                | PointType::Cross
+                | PointType::X => format!(
+                    r#"<path d="{d}"{style}/>"#,
+                    d = symbol_path(
+                        &point_type,
+                        NumCast::from(self.x()).unwrap_or(0.0),
+                        NumCast::from(self.y()).unwrap_or(0.0),
+                        style.radius as f64,
+                    )
+                    .unwrap_or_default(),
                     style = style,
-                    path = style.icon_svg_path.clone().unwrap_or("".into()),
-                    w = width,
-                    h = height,
-                    mx = min_x,
-                    my = min_y,
-                    vbw = vb_width,
-                    vbh = vb_height,
-                    x = x - (width as f64 / 2.0),
-                    y = y - (height as f64 / 2.0),
-                    text = text,
-                )
-            }
-            PointType::Symbol |
-            PointType::Circle => format!(
-                r#"<circle cx="{x:?}" cy="{y:?}" r="{radius}"{style}/>"#,
-                x = self.x(),
-                y = self.y(),
-                radius = style.radius,
-                style = style,
-            )
+                ),
+                PointType::Pin => format!(
+                    r#"<path fill-rule="evenodd" d="{d}"{style}/>"#,
+                    d = pin_path(
+                        NumCast::from(self.x()).unwrap_or(0.0),
+                        NumCast::from(self.y()).unwrap_or(0.0),
+                        style.radius as f64,
+                    ),
+                    style = style,
+                ),
+                // registered symbols take precedence over external icons
+                PointType::Symbol if style.symbol.is_some() || style.icon_href.is_some() => {
+                    format!(
+                        r#"<use {attribute}="{href}" x="{x}" y="{y}" width="{size}" height="{size}"{style}/>"#,
+                        attribute = style.profile.href(),
+                        href = style
+                            .symbol
+                            .as_deref()
+                            .map(|name| format!("#{}", symbol_id(name)))
+                            .or_else(|| style
+                                .icon_href
+                                .as_deref()
+                                .map(|href| Escaped(href).to_string()))
+                            .unwrap_or_default(),
+                        x = Num(NumCast::from(self.x()).unwrap_or(0.0) - style.radius as f64),
+                        y = Num(NumCast::from(self.y()).unwrap_or(0.0) - style.radius as f64),
+                        size = style.radius * 2.0,
+                        style = style,
+                    )
+                }
+                PointType::Symbol | PointType::Circle => format!(
+                    r#"<circle cx="{x}" cy="{y}" r="{radius}"{style}/>"#,
+                    x = Num(self.x()),
+                    y = Num(self.y()),
+                    radius = style.radius,
+                    style = style,
+                ),
             }
         } else {
             format!(
-                r#"<circle alt="point_type_none" cx="{x:?}" cy="{y:?}" r="{radius}"{style}/>"#,
-                x = self.x(),
-                y = self.y(),
+                r#"<circle cx="{x}" cy="{y}" r="{radius}"{style}/>"#,
+                x = Num(self.x()),
+                y = Num(self.y()),
                 radius = style.radius,
                 style = style,
             )
-        }
+        };
+        marker + &labels_str(self, style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
-        let radius = style.radius + style.stroke_width.unwrap_or(1.0);
-        ViewBox::new(
-            NumCast::from(self.x()).unwrap_or(0f32) - radius,
-            NumCast::from(self.y()).unwrap_or(0f32) - radius,
-            NumCast::from(self.x()).unwrap_or(0f32) + radius,
-            NumCast::from(self.y()).unwrap_or(0f32) + radius,
-        )
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.viewbox(&style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.viewbox(&style);
+        }
+        // a radius in pixels has no known size in geometry units
+        let radius = match style.radius_unit {
+            RadiusUnit::Map => (style.radius + style.stroke_width.unwrap_or(1.0)) as f64,
+            RadiusUnit::Pixels => 0.0,
+        };
+        // coordinates which don't fit in a f64 don't extend the viewbox
+        let (x, y): (f64, f64) = match (NumCast::from(self.x()), NumCast::from(self.y())) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return ViewBox::default(),
+        };
+        // pins stand above their coordinate instead of being centered on it
+        let y = match style.point_type {
+            Some(PointType::Pin) => y - style.radius as f64,
+            _ => y,
+        };
+        ViewBox::new(x - radius, y - radius, x + radius, y + radius)
+    }
+
+    // point labels sit on the side of the marker given by their alignment, above it by default
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.labels(&style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.labels(&style);
+        }
+        style
+            .label
+            .as_ref()
+            .map(|label| {
+                let anchor = style.text_anchor.unwrap_or(TextAnchor::Middle);
+                let baseline = style
+                    .dominant_baseline
+                    .unwrap_or(DominantBaseline::TextAfterEdge);
+                let offset = (style.radius + style.stroke_width.unwrap_or(1.0)) as f64;
+                let x: f64 = NumCast::from(self.x()).unwrap_or(0.0);
+                let y: f64 = NumCast::from(self.y()).unwrap_or(0.0);
+                Label::new(
+                    x + (1.0 - 2.0 * anchor.offset()) * offset,
+                    y + (1.0 - 2.0 * baseline.offset()) * offset,
+                    label,
+                    &Style {
+                        text_anchor: Some(anchor),
+                        dominant_baseline: Some(baseline),
+                        ..style.clone()
+                    },
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+/// Concatenates the elements of the items in a buffer allocated once.
+fn concat<'a, T: ToSvgStr + 'a>(
+    items: impl Iterator<Item = &'a T> + Clone,
+    style: &Style,
+) -> String {
+    let mut svg = String::with_capacity(items.clone().map(ToSvgStr::estimated_len).sum());
+    for item in items {
+        svg.push_str(&item.to_svg_str(style));
     }
+    svg
 }
 
 impl<T: CoordNum> ToSvgStr for MultiPoint<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.0.iter().map(|point| point.to_svg_str(style)).collect()
+        concat(self.0.iter(), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -108,81 +308,203 @@ impl<T: CoordNum> ToSvgStr for MultiPoint<T> {
             view_box.add(&point.viewbox(style))
         })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.0
+            .iter()
+            .flat_map(|point| point.labels(style))
+            .collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.0.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.iter().all(ToSvgStr::is_finite)
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for Line<T> {
     fn to_svg_str(&self, style: &Style) -> String {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.to_svg_str(&style);
+        }
+        // line strings handle the geographic options
+        if style.great_circle_step.is_some() || style.wrap_antimeridian {
+            return LineString::from(vec![self.start, self.end]).to_svg_str(style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.to_svg_str(&style);
+        }
+        let mut path = PathBuilder::new(style.path_encoding);
+        path.move_to(self.start);
+        path.line_to(self.end);
         format!(
-            r#"<path d="M {x1:?} {y1:?} L {x2:?} {y2:?}"{style}/>"#,
-            x1 = self.start.x,
-            y1 = self.start.y,
-            x2 = self.end.x,
-            y2 = self.end.y,
+            r#"<path d="{d}"{style}/>{lengths}"#,
+            d = path.finish(),
             style = style,
+            lengths = segment_lengths(Some(*self), style),
         )
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.viewbox(&style);
+        }
+        // line strings handle the geographic options
+        if style.great_circle_step.is_some() || style.wrap_antimeridian {
+            return LineString::from(vec![self.start, self.end]).viewbox(style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.viewbox(&style);
+        }
         let style = Style {
             radius: 0.0,
             ..style.clone()
         };
         self.start.viewbox(&style).add(&self.end.viewbox(&style))
     }
+
+    fn estimated_len(&self) -> usize {
+        ELEMENT_LEN + 2 * COORD_LEN
+    }
+
+    fn is_finite(&self) -> bool {
+        self.start.is_finite() && self.end.is_finite()
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for LineString<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        let d = self
-            .lines()
-            .map(|line| {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.to_svg_str(&style);
+        }
+        if let Some(step) = style.great_circle_step {
+            return densify(self, step).to_svg_str(&Style {
+                great_circle_step: None,
+                ..style.clone()
+            });
+        }
+        if style.wrap_antimeridian {
+            return split_line_string(self).to_svg_str(&Style {
+                wrap_antimeridian: false,
+                ..style.clone()
+            });
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.to_svg_str(&style);
+        }
+        #[cfg(feature = "geo")]
+        if let Some(epsilon) = style.simplify_epsilon {
+            return simplify_line_string(self, epsilon).to_svg_str(&Style {
+                simplify_epsilon: None,
+                ..style.clone()
+            });
+        }
+        if let Some(step) = style.snap_grid {
+            return snap_line_string(self, step).to_svg_str(&Style {
+                snap_grid: None,
+                ..style.clone()
+            });
+        }
+
+        let element = match (style.element_mode, style.smoothing) {
+            (ElementMode::Primitive, None) => format!(
+                r#"<polyline points="{points}"{style}/>"#,
+                points = points_str(self),
+                style = style,
+            ),
+            (_, smoothing) => format!(
+                r#"<path d="{d}"{style}/>"#,
+                d = match smoothing {
+                    Some(smoothing) => smooth_path(self, smoothing),
+                    None => {
+                        let mut path = PathBuilder::new(style.path_encoding);
+                        let mut coords = self.coords();
+                        if let Some(first) = coords.next() {
+                            path.move_to(*first);
+                            for coord in coords {
+                                path.line_to(*coord);
+                            }
+                        }
+                        path.finish()
+                    }
+                },
+                style = style,
+            ),
+        };
+
+        let text_part = if let (Some(text), Some(id)) = (style.text.clone(), style.id.clone()) {
+            with_halo_copy(style, |style| {
                 format!(
-                    "M {x1:?} {y1:?} L {x2:?}  {y2:?}",
-                    x1 = line.start.x,
-                    y1 = line.start.y,
-                    x2 = line.end.x,
-                    y2 = line.end.y,
+                    r##"<text class="{class}"{text_style}><textPath {attribute}="#{path_ref}"{start_offset}>{text}</textPath></text>"##,
+                    text_style = TextStyle(style),
+                    class = Escaped(style.text_classes.as_deref().unwrap_or("")),
+                    attribute = style.profile.href(),
+                    path_ref = Escaped(&id),
+                    text = Escaped(&text),
+                    start_offset = style
+                        .text_start_offset
+                        .map(|o| format!(r#" startOffset="{}""#, o))
+                        .unwrap_or("".into()),
                 )
             })
-            .reduce(|a, b| format!("{} {}", a, b))
-            .unwrap_or("".into());
-
-        let text_part = if let (Some(text), Some(id)) = (style.text.clone(), style.id.clone()) {
-            format!(
-                r##"<text class="{class}"><textPath xlink:href="#{path_ref}"{start_offset}>{text}<textPath/></text>"##,
-                class = style.text_classes.as_ref().unwrap_or(&"".into()),
-                path_ref = id,
-                text = text,
-                start_offset = style
-                    .text_start_offset
-                    .and_then(|o| Some(format!(r#"startOffset="{}""#, o)))
-                    .unwrap_or("".into()),
-            )
         } else {
             "".into()
         };
 
-        format!(
-            r#"<path d="{d}"{style}/>{txt}"#,
-            d = d,
-            style = style,
-            txt = text_part,
-        )
+        let centroid = centroid_marker(line_string_centroid(self), style);
+        element
+            + &direction_arrows(self, style)
+            + &segment_lengths(self.lines(), style)
+            + &text_part
+            + &vertex_markers(Some(self), style)
+            + &coordinate_labels(Some(self), style)
+            + &centroid
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.viewbox(&style);
+        }
+        if let Some(step) = style.great_circle_step {
+            return densify(self, step).viewbox(&Style {
+                great_circle_step: None,
+                ..style.clone()
+            });
+        }
+        if style.wrap_antimeridian {
+            return split_line_string(self).viewbox(&Style {
+                wrap_antimeridian: false,
+                ..style.clone()
+            });
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.viewbox(&style);
+        }
         self.lines().fold(ViewBox::default(), |view_box, line| {
             view_box.add(&line.viewbox(style))
         })
     }
+
+    fn estimated_len(&self) -> usize {
+        ELEMENT_LEN + self.0.len() * COORD_LEN
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.iter().all(ToSvgStr::is_finite)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        line_string_warnings(self)
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for MultiLineString<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|line_string| line_string.to_svg_str(style))
-            .collect()
+        concat(self.0.iter(), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -192,31 +514,147 @@ impl<T: CoordNum> ToSvgStr for MultiLineString<T> {
                 view_box.add(&line_string.viewbox(style))
             })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.0
+            .iter()
+            .flat_map(|line_string| line_string.labels(style))
+            .collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.0.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.iter().all(ToSvgStr::is_finite)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        self.0.iter().flat_map(ToSvgStr::warnings).collect()
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for Polygon<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        use std::fmt::Write;
-        let mut path = String::new();
-        for contour in std::iter::once(self.exterior()).chain(self.interiors().iter()) {
-            let mut points = contour.points_iter();
-            if let Some(first_point) = points.next() {
-                write!(path, "M {:?} {:?}", first_point.x(), first_point.y()).unwrap()
-            }
-            for point in points {
-                write!(path, " L {:?} {:?}", point.x(), point.y()).unwrap();
-            }
-            write!(path, " Z ").unwrap();
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.to_svg_str(&style);
+        }
+        if style.wrap_antimeridian {
+            return unwrap_polygon(self).to_svg_str(&Style {
+                wrap_antimeridian: false,
+                ..style.clone()
+            });
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.to_svg_str(&style);
+        }
+        #[cfg(feature = "geo")]
+        if let Some(epsilon) = style.simplify_epsilon {
+            return simplify_polygon(self, epsilon).to_svg_str(&Style {
+                simplify_epsilon: None,
+                ..style.clone()
+            });
+        }
+        if let Some(step) = style.snap_grid {
+            return snap_polygon(self, step).to_svg_str(&Style {
+                snap_grid: None,
+                ..style.clone()
+            });
+        }
+        if style.normalize_winding {
+            return normalize_winding(self).to_svg_str(&Style {
+                normalize_winding: false,
+                ..style.clone()
+            });
         }
 
+        if style.element_mode == ElementMode::Primitive && self.interiors().is_empty() {
+            return format!(
+                r#"<polygon points="{points}"{style}/>{labels}{vertices}{coordinates}{centroid}"#,
+                points = points_str(self.exterior()),
+                style = style,
+                labels = labels_str(self, style),
+                vertices = vertex_markers(Some(self.exterior()), style),
+                coordinates = coordinate_labels(Some(self.exterior()), style),
+                centroid = centroid_marker(polygon_centroid(self), style),
+            );
+        }
+
+        let rings = core::iter::once(self.exterior()).chain(self.interiors().iter());
+        let element = match &style.hole_style {
+            Some(hole_style) if !self.interiors().is_empty() => {
+                // the holes are cut out of the fill but not outlined with the exterior
+                let no_stroke = Style {
+                    stroke_color: None,
+                    stroke_width: None,
+                    stroke_opacity: None,
+                    ..style.clone()
+                };
+                let stroke = Style {
+                    stroke_color: style.stroke_color,
+                    stroke_width: style.stroke_width,
+                    stroke_opacity: style.stroke_opacity,
+                    ..Style::default()
+                };
+                let mut element = format!(
+                    r#"<path fill-rule="evenodd" d="{path}"{style}/><path d="{exterior}" fill="none"{stroke}/>"#,
+                    path = rings_path(rings, style),
+                    style = no_stroke,
+                    exterior = rings_path(Some(self.exterior()), style),
+                    stroke = stroke,
+                );
+                for interior in self.interiors() {
+                    element.push_str(&format!(
+                        r#"<path d="{path}"{fill}{style}/>"#,
+                        path = rings_path(Some(interior), style),
+                        fill = if hole_style.fill.is_none() {
+                            r#" fill="none""#
+                        } else {
+                            ""
+                        },
+                        style = hole_style,
+                    ));
+                }
+                element
+            }
+            _ => format!(
+                r#"<path fill-rule="evenodd" d="{path}"{style}/>"#,
+                path = rings_path(rings, style),
+                style = style,
+            ),
+        };
+
         format!(
-            r#"<path fill-rule="evenodd" d="{path}"{style}/>"#,
-            path = path,
-            style = style,
+            "{element}{labels}{vertices}{coordinates}{centroid}",
+            element = element,
+            labels = labels_str(self, style),
+            vertices = vertex_markers(
+                core::iter::once(self.exterior()).chain(self.interiors()),
+                style
+            ),
+            coordinates = coordinate_labels(
+                core::iter::once(self.exterior()).chain(self.interiors()),
+                style
+            ),
+            centroid = centroid_marker(polygon_centroid(self), style),
         )
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.viewbox(&style);
+        }
+        if style.wrap_antimeridian {
+            return unwrap_polygon(self).viewbox(&Style {
+                wrap_antimeridian: false,
+                ..style.clone()
+            });
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.viewbox(&style);
+        }
         self.exterior()
             .lines()
             .chain(
@@ -228,16 +666,154 @@ impl<T: CoordNum> ToSvgStr for Polygon<T> {
                 view_box.add(&line_string.viewbox(style))
             })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.labels(&style);
+        }
+        // measured on the lon/lat coordinates
+        #[cfg(feature = "geo")]
+        if let (Some(_), true) = (&style.area_label, style.geodesic_area) {
+            return self.labels(&area_label_style(style, geodesic_area(self)));
+        }
+        if style.wrap_antimeridian {
+            return unwrap_polygon(self).labels(&Style {
+                wrap_antimeridian: false,
+                ..style.clone()
+            });
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.labels(&style);
+        }
+        if style.area_label.is_some() {
+            return self.labels(&area_label_style(style, unsigned_area(self)));
+        }
+        style
+            .label
+            .as_ref()
+            .and_then(|label| {
+                let (x, y) = representative_point(self)?;
+                Some(Label::new(x, y, label, style))
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        let rings = core::iter::once(self.exterior()).chain(self.interiors());
+        ELEMENT_LEN + rings.map(|ring| ring.0.len()).sum::<usize>() * COORD_LEN
+    }
+
+    fn is_finite(&self) -> bool {
+        self.exterior().is_finite() && self.interiors().iter().all(ToSvgStr::is_finite)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        polygon_warnings(self)
+    }
+}
+
+/// Path data of the closed rings.
+fn rings_path<'a, T: CoordNum + 'a>(
+    rings: impl IntoIterator<Item = &'a LineString<T>>,
+    style: &Style,
+) -> String {
+    let mut path = PathBuilder::new(style.path_encoding);
+    for ring in rings {
+        let mut coords = ring.coords();
+        if let Some(first) = coords.next() {
+            path.move_to(*first);
+            for coord in coords {
+                path.line_to(*coord);
+            }
+            path.close();
+        }
+    }
+    path.finish()
+}
+
+fn points_str<T: CoordNum>(line_string: &LineString<T>) -> String {
+    line_string
+        .coords()
+        .map(|coord| format!("{},{}", Num(coord.x), Num(coord.y)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn labels_str<G: ToSvgStr>(geometry: &G, style: &Style) -> String {
+    geometry
+        .labels(style)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Whether the style sets options of polygons which a `<rect>` can't show, such as vertex
+/// markers or snapping, so that rectangles are drawn as polygons.
+fn needs_polygon(style: &Style) -> bool {
+    style.wrap_antimeridian
+        || style.snap_grid.is_some()
+        || style.debug_vertices
+        || style.centroid_marker.is_some()
+        || style.coordinate_labels.is_some()
 }
 
 impl<T: CoordNum> ToSvgStr for Rect<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        Polygon::from(*self).to_svg_str(style)
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.to_svg_str(&style);
+        }
+        if needs_polygon(style) {
+            return Polygon::from(*self).to_svg_str(style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.to_svg_str(&style);
+        }
+        format!(
+            r#"<rect x="{x}" y="{y}" width="{width}" height="{height}"{corner_radius}{style}/>{labels}"#,
+            x = Num(self.min().x),
+            y = Num(self.min().y),
+            width = Num(self.width()),
+            height = Num(self.height()),
+            corner_radius = style
+                .corner_radius
+                .map(|radius| format!(r#" rx="{}""#, radius))
+                .unwrap_or_default(),
+            style = style,
+            labels = labels_str(self, style),
+        )
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.viewbox(&style);
+        }
+        if needs_polygon(style) {
+            return Polygon::from(*self).viewbox(style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.viewbox(&style);
+        }
         Polygon::from(*self).viewbox(style)
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        if let Some((swapped, style)) = swapped(self, style) {
+            return swapped.labels(&style);
+        }
+        if needs_polygon(style) {
+            return Polygon::from(*self).labels(style);
+        }
+        if let Some((projected, style)) = projected(self, style) {
+            return projected.labels(&style);
+        }
+        Polygon::from(*self).labels(style)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.min().is_finite() && self.max().is_finite()
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for Triangle<T> {
@@ -248,14 +824,27 @@ impl<T: CoordNum> ToSvgStr for Triangle<T> {
     fn viewbox(&self, style: &Style) -> ViewBox {
         Polygon::new(self.to_array().iter().cloned().collect(), vec![]).viewbox(style)
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        Polygon::new(self.to_array().iter().cloned().collect(), vec![]).labels(style)
+    }
+
+    fn estimated_len(&self) -> usize {
+        ELEMENT_LEN + 4 * COORD_LEN
+    }
+
+    fn is_finite(&self) -> bool {
+        self.to_array().iter().all(ToSvgStr::is_finite)
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for MultiPolygon<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|polygons| polygons.to_svg_str(style))
-            .collect()
+        let part_style = Style {
+            label: None,
+            ..style.clone()
+        };
+        concat(self.0.iter(), &part_style) + &labels_str(self, style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -265,6 +854,32 @@ impl<T: CoordNum> ToSvgStr for MultiPolygon<T> {
                 view_box.add(&polygons.viewbox(style))
             })
     }
+
+    // a multipolygon gets a single label, placed on its largest part
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.0
+            .iter()
+            .max_by(|a, b| {
+                unsigned_area(a)
+                    .partial_cmp(&unsigned_area(b))
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .map(|largest| largest.labels(style))
+            .unwrap_or_default()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.0.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.iter().all(ToSvgStr::is_finite)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        self.0.iter().flat_map(ToSvgStr::warnings).collect()
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for Geometry<T> {
@@ -275,7 +890,7 @@ impl<T: CoordNum> ToSvgStr for Geometry<T> {
             Line(line) => line.to_svg_str(style),
             LineString(line_tring) => line_tring.to_svg_str(style),
             Triangle(triangle) => triangle.to_polygon().to_svg_str(style),
-            Rect(rect) => rect.to_polygon().to_svg_str(style),
+            Rect(rect) => rect.to_svg_str(style),
             Polygon(polygon) => polygon.to_svg_str(style),
             MultiPoint(multi_point) => multi_point.to_svg_str(style),
             MultiLineString(multi_line_string) => multi_line_string.to_svg_str(style),
@@ -299,14 +914,72 @@ impl<T: CoordNum> ToSvgStr for Geometry<T> {
             GeometryCollection(geometry_collection) => geometry_collection.viewbox(style),
         }
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        use Geometry::*;
+        match self {
+            Point(point) => point.labels(style),
+            Line(line) => line.labels(style),
+            LineString(line_tring) => line_tring.labels(style),
+            Triangle(triangle) => triangle.to_polygon().labels(style),
+            Rect(rect) => rect.to_polygon().labels(style),
+            Polygon(polygon) => polygon.labels(style),
+            MultiPoint(multi_point) => multi_point.labels(style),
+            MultiLineString(multi_line_string) => multi_line_string.labels(style),
+            MultiPolygon(multi_polygon) => multi_polygon.labels(style),
+            GeometryCollection(geometry_collection) => geometry_collection.labels(style),
+        }
+    }
+
+    fn estimated_len(&self) -> usize {
+        use Geometry::*;
+        match self {
+            Point(point) => point.estimated_len(),
+            Line(line) => line.estimated_len(),
+            LineString(line_string) => line_string.estimated_len(),
+            Triangle(triangle) => triangle.estimated_len(),
+            Rect(rect) => rect.estimated_len(),
+            Polygon(polygon) => polygon.estimated_len(),
+            MultiPoint(multi_point) => multi_point.estimated_len(),
+            MultiLineString(multi_line_string) => multi_line_string.estimated_len(),
+            MultiPolygon(multi_polygon) => multi_polygon.estimated_len(),
+            GeometryCollection(geometry_collection) => geometry_collection.estimated_len(),
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        use Geometry::*;
+        match self {
+            Point(point) => point.is_finite(),
+            Line(line) => line.is_finite(),
+            LineString(line_string) => line_string.is_finite(),
+            Triangle(triangle) => triangle.is_finite(),
+            Rect(rect) => rect.is_finite(),
+            Polygon(polygon) => polygon.is_finite(),
+            MultiPoint(multi_point) => multi_point.is_finite(),
+            MultiLineString(multi_line_string) => multi_line_string.is_finite(),
+            MultiPolygon(multi_polygon) => multi_polygon.is_finite(),
+            GeometryCollection(geometry_collection) => geometry_collection.is_finite(),
+        }
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        use Geometry::*;
+        match self {
+            LineString(line_string) => line_string.warnings(),
+            Polygon(polygon) => polygon.warnings(),
+            MultiLineString(multi_line_string) => multi_line_string.warnings(),
+            MultiPolygon(multi_polygon) => multi_polygon.warnings(),
+            GeometryCollection(geometry_collection) => geometry_collection.warnings(),
+            _ => vec![],
+        }
+    }
 }
 
 impl<T: CoordNum> ToSvgStr for GeometryCollection<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|geometry| geometry.to_svg_str(style))
-            .collect()
+        concat(self.0.iter(), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -316,13 +989,31 @@ impl<T: CoordNum> ToSvgStr for GeometryCollection<T> {
                 view_box.add(&geometry.viewbox(style))
             })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.0
+            .iter()
+            .flat_map(|geometry| geometry.labels(style))
+            .collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.0.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.iter().all(ToSvgStr::is_finite)
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        self.0.iter().flat_map(ToSvgStr::warnings).collect()
+    }
 }
 
-impl<'a, T: ToSvgStr> ToSvgStr for &'a [T] {
+impl<T: ToSvgStr> ToSvgStr for &[T] {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.iter()
-            .map(|geometry| geometry.to_svg_str(style))
-            .collect()
+        concat(self.iter(), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -330,13 +1021,36 @@ impl<'a, T: ToSvgStr> ToSvgStr for &'a [T] {
             view_box.add(&item.viewbox(style))
         })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.iter().flat_map(|item| item.labels(style)).collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.iter().all(ToSvgStr::is_finite)
+    }
+
+    fn uses_xlink(&self, style: &Style) -> bool {
+        self.iter().any(|item| item.uses_xlink(style))
+    }
+
+    fn ids(&self, style: &Style) -> Vec<String> {
+        self.iter().flat_map(|item| item.ids(style)).collect()
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        self.iter().flat_map(ToSvgStr::warnings).collect()
+    }
 }
 
 impl<T: ToSvgStr> ToSvgStr for Vec<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        self.iter()
-            .map(|geometry| geometry.to_svg_str(style))
-            .collect()
+        concat(self.iter(), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
@@ -344,12 +1058,42 @@ impl<T: ToSvgStr> ToSvgStr for Vec<T> {
             view_box.add(&item.viewbox(style))
         })
     }
+
+    fn labels(&self, style: &Style) -> Vec<Label> {
+        self.iter().flat_map(|item| item.labels(style)).collect()
+    }
+
+    fn estimated_len(&self) -> usize {
+        self.iter().map(ToSvgStr::estimated_len).sum()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.iter().all(ToSvgStr::is_finite)
+    }
+
+    fn uses_xlink(&self, style: &Style) -> bool {
+        self.iter().any(|item| item.uses_xlink(style))
+    }
+
+    fn ids(&self, style: &Style) -> Vec<String> {
+        self.iter().flat_map(|item| item.ids(style)).collect()
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<GeometryWarning> {
+        self.iter().flat_map(ToSvgStr::warnings).collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Color, ToSvg};
-    use geo_types::{LineString, Point, Polygon};
+    use crate::{
+        AspectAlign, Color, DominantBaseline, ElementMode, Filter, FontStyle, FontWeight, Gradient,
+        MeetOrSlice, PathEncoding, PointType, Projection, RadiusUnit, Style, Svg, SvgProfile,
+        TextAnchor, ToSvg, ToSvgStr,
+    };
+    use crate::{Symbol, SymbolRegistry};
+    use geo_types::{Coord, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon, Rect};
 
     #[test]
     fn test_point() {
@@ -373,6 +1117,797 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_polygon_label() {
+        let svg = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]),
+            vec![],
+        )
+        .to_svg()
+        .with_label("A")
+        .to_string();
+        assert!(svg.contains(
+            r#"<text x="5.0" y="5.0" text-anchor="middle" dominant-baseline="central">A</text>"#
+        ));
+    }
+
+    #[test]
+    fn test_text_halo() {
+        let svg = Point::new(0.0, 0.0)
+            .to_svg()
+            .with_point_type(Some(PointType::Text))
+            .with_text(Some("A".into()), None, None)
+            .with_stroke_color(Color::Named("red"))
+            .with_text_halo(Color::Named("white"), 2.0)
+            .to_string();
+        assert!(svg.contains(
+            r#" stroke="white" stroke-width="2" stroke-linejoin="round" paint-order="stroke">A</text>"#
+        ));
+        assert!(!svg.contains("red"));
+    }
+
+    #[test]
+    fn test_svg_profile() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_id("road".into())
+            .with_text(Some("Main St".into()), Some(2.0), None)
+            .to_string();
+        assert!(
+            svg.contains(r##"<textPath href="#road" startOffset="2">Main St</textPath></text>"##)
+        );
+
+        let svg = line_string
+            .to_svg()
+            .with_id("road".into())
+            .with_text(Some("Main St".into()), None, None)
+            .with_text_halo(Color::Named("white"), 2.0)
+            .with_profile(SvgProfile::Svg11)
+            .to_string();
+        assert!(svg.contains(r#"xmlns:xlink="http://www.w3.org/1999/xlink""#));
+        assert!(svg.contains(concat!(
+            r##"<text class="" stroke="white" stroke-width="2" stroke-linejoin="round"><textPath xlink:href="#road">Main St</textPath></text>"##,
+            r##"<text class=""><textPath xlink:href="#road">Main St</textPath></text>"##,
+        )));
+        assert!(!svg.contains("paint-order"));
+    }
+
+    #[test]
+    fn test_preserve_aspect_ratio() {
+        let point = Point::new(0.0, 0.0);
+        let svg = point
+            .to_svg()
+            .with_preserve_aspect_ratio(AspectAlign::XMidYMid, MeetOrSlice::Slice)
+            .to_string();
+        assert!(svg.contains(r#"preserveAspectRatio="xMidYMid slice""#));
+        let svg = point
+            .to_svg()
+            .with_preserve_aspect_ratio(AspectAlign::None, MeetOrSlice::Slice)
+            .to_string();
+        assert!(svg.contains(r#"preserveAspectRatio="none""#));
+    }
+
+    #[test]
+    fn test_transform_attributes() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_translate(2.0, -1.5)
+            .with_rotate(45.0, 0.5, 0.5)
+            .with_scale(2.0)
+            .to_string();
+        assert!(svg.contains(
+            r#"<path d="M 0.0 0.0 L 1.0 1.0" transform="translate(2, -1.5) rotate(45, 0.5, 0.5) scale(2)"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_focus() {
+        let parcel = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 0.0)]),
+            vec![],
+        );
+        let neighbour = LineString::from(vec![(4.0, 0.0), (40.0, 0.0)]);
+        let svg = parcel
+            .to_svg()
+            .and(neighbour.to_svg().with_stroke_color(Color::Named("grey")))
+            .with_focus(&parcel, 2.0)
+            .to_string();
+        assert!(svg.contains(r#"viewBox="-3 -3 10 10""#));
+        assert!(svg.contains(
+            r#"<clipPath id="clip-0"><rect x="-3" y="-3" width="10" height="10"/></clipPath>"#
+        ));
+        assert!(svg.contains(r#"<g clip-path="url(#clip-0)"><path"#));
+        assert!(svg.contains(r#"<path d="M 4.0 0.0 L 40.0 0.0" stroke="grey"/></g>"#));
+    }
+
+    #[test]
+    fn test_canvas() {
+        let line_string = LineString::from(vec![(1.0, 1.0), (9.0, 4.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_canvas(120.0, 120.0, 10.0)
+            .with_background(Color::Named("white"))
+            .to_string();
+        assert!(svg.starts_with(
+            r#"<svg width="120" height="120" xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="-1 -3.5 12 12"><rect x="-1" y="-3.5" width="12" height="12" fill="white"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_rounded_viewbox() {
+        let line_string = LineString::from(vec![(0.3, 0.2), (17.4, 8.6)]);
+        let svg = line_string.to_svg().with_rounded_viewbox(5.0).to_string();
+        assert!(svg.contains(r#"viewBox="-5 -5 25 15""#));
+        let svg = line_string
+            .to_svg()
+            .with_rounded_viewbox(5.0)
+            .with_custom_viewbox(0.0, 0.0, 1.0, 1.0)
+            .to_string();
+        assert!(svg.contains(r#"viewBox="0 0 1 1""#));
+        let svg = line_string.to_svg().with_rounded_viewbox(0.0).to_string();
+        assert!(svg.contains(r#"viewBox="-0.7 -0.8 19.1 10.4""#));
+    }
+
+    #[test]
+    fn test_margin() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let svg = rect.to_svg().with_margin(5.0).to_string();
+        assert!(svg.contains(r#"viewBox="-6 -6 22 22""#));
+        let svg = rect
+            .to_svg()
+            .with_margin(5.0)
+            .with_rounded_viewbox(4.0)
+            .to_string();
+        assert!(svg.contains(r#"viewBox="-8 -8 24 24""#));
+        let svg = rect
+            .to_svg()
+            .with_margin(5.0)
+            .with_custom_viewbox(0.0, 0.0, 1.0, 1.0)
+            .to_string();
+        assert!(svg.contains(r#"viewBox="0 0 1 1""#));
+    }
+
+    #[test]
+    fn test_namespace_declarations() {
+        let point = Point::new(0.0, 0.0);
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let road = line_string.to_svg().with_id("road".into()).with_text(
+            Some("Main St".into()),
+            None,
+            None,
+        );
+        let svg = point.to_svg().and(road.clone()).to_string();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" "#));
+
+        let svg = point
+            .to_svg()
+            .and(road.with_profile(SvgProfile::Svg11))
+            .to_string();
+        assert!(svg.starts_with(
+            r#"<svg xmlns:xlink="http://www.w3.org/1999/xlink" xmlns="http://www.w3.org/2000/svg" "#
+        ));
+
+        // found from the styles, not the text
+        let svg = point.to_svg().with_label("see xlink: docs").to_string();
+        assert!(!svg.contains("xmlns:xlink"));
+    }
+
+    #[test]
+    fn test_font_styling() {
+        let svg = Point::new(0.0, 0.0)
+            .to_svg()
+            .with_label("A")
+            .with_font_family("Noto Sans")
+            .with_font_size(12.0)
+            .with_font_weight(FontWeight::Bold)
+            .with_font_style(FontStyle::Italic)
+            .to_string();
+        assert!(svg.contains(
+            r#" font-family="Noto Sans" font-size="12" font-weight="bold" font-style="italic">A</text>"#
+        ));
+    }
+
+    #[test]
+    fn test_point_label_alignment() {
+        let svg = Point::new(0.0, 0.0)
+            .to_svg()
+            .with_radius(2.0)
+            .with_label("A")
+            .with_text_anchor(TextAnchor::Start)
+            .with_dominant_baseline(DominantBaseline::Central)
+            .to_string();
+        assert!(svg.contains(
+            r#"<text x="3.0" y="0.0" text-anchor="start" dominant-baseline="central">A</text>"#
+        ));
+    }
+
+    #[test]
+    fn test_multiline_text() {
+        let svg = Point::new(1.0, 2.0)
+            .to_svg()
+            .with_point_type(Some(PointType::Text))
+            .with_text(Some("Name\nElevation: 430 m".into()), None, None)
+            .with_line_height(1.5)
+            .to_string();
+        assert!(svg.contains(
+            r#"><tspan x="1.0" dy="0em">Name</tspan><tspan x="1.0" dy="1.5em">Elevation: 430 m</tspan></text>"#
+        ));
+    }
+
+    #[test]
+    fn test_registered_symbol() {
+        let mut symbols = SymbolRegistry::new();
+        symbols.register(
+            "pin",
+            Symbol::new("M0 0L10 0L5 20Z", (0.0, 0.0, 10.0, 20.0)).with_anchor(5.0, 20.0),
+        );
+        let points = MultiPoint::from(vec![(10.0, 10.0), (20.0, 20.0)]);
+        let svg = points
+            .to_svg()
+            .with_radius(4.0)
+            .with_symbol("pin")
+            .with_symbols(symbols)
+            .to_string();
+        assert_eq!(svg.matches("<symbol").count(), 1);
+        assert!(svg.contains(
+            r#"<defs><symbol id="symbol-pin" viewBox="-15 0 40 40"><path d="M0 0L10 0L5 20Z"/></symbol></defs>"#
+        ));
+        assert!(
+            svg.contains(r##"<use href="#symbol-pin" x="16.0" y="16.0" width="8" height="8"/>"##)
+        );
+    }
+
+    #[test]
+    fn test_icon_href() {
+        let svg = Point::new(10.0, 10.0)
+            .to_svg()
+            .with_radius(5.0)
+            .with_icon_href("icons.svg#bus")
+            .to_string();
+        assert!(
+            svg.contains(r#"<use href="icons.svg#bus" x="5.0" y="5.0" width="10" height="10"/>"#)
+        );
+    }
+
+    #[test]
+    fn test_glyph() {
+        let svg = Point::new(1.0, 2.0)
+            .to_svg()
+            .with_point_type(Some(PointType::Glyph('★')))
+            .with_radius(6.0)
+            .to_string();
+        assert!(svg.contains(
+            r#"<text x="1.0" y="2.0" text-anchor="middle" dominant-baseline="central" font-size="12">★</text>"#
+        ));
+    }
+
+    #[test]
+    fn test_pixel_radius() {
+        let svg = Point::new(1.0, 2.0)
+            .to_svg()
+            .with_radius(3.0)
+            .with_radius_unit(RadiusUnit::Pixels)
+            .with_fill_color(Color::Named("red"))
+            .to_string();
+        assert!(svg.contains(
+            r#"<g><path d="M1.0 2.0h0" stroke="red" stroke-width="6" stroke-linecap="round" vector-effect="non-scaling-stroke"/></g>"#
+        ));
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn test_simplify() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (5.0, 0.1), (10.0, 0.0)]);
+        let svg = line_string.to_svg().with_simplify_epsilon(1.0).to_string();
+        assert!(svg.contains(r#"d="M 0.0 0.0 L 10.0 0.0""#));
+    }
+
+    #[cfg(not(feature = "geo"))]
+    #[test]
+    fn test_simplify_without_geo() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (5.0, 0.1), (10.0, 0.0)]);
+        let style = Style {
+            simplify_epsilon: Some(1.0),
+            ..Style::default()
+        };
+        assert!(line_string
+            .to_svg_str(&style)
+            .contains(r#"d="M 0.0 0.0 L 5.0 0.1 L 10.0 0.0""#));
+    }
+
+    #[test]
+    fn test_debug_vertices() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let svg = line_string.to_svg().with_debug_vertices().to_string();
+        assert!(svg.contains(
+            r#"<path d="M 0.0 0.0 L 10.0 0.0"/><g class="debug-vertices" fill="red" font-size="3"><circle cx="0.0" cy="0.0" r="1"/><text x="0.0" y="0.0" dx="1" dy="-1">0</text><circle cx="10.0" cy="0.0" r="1"/>"#
+        ));
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)]),
+            vec![],
+        );
+        let svg = polygon.to_svg().with_debug_vertices().to_string();
+        assert_eq!(svg.matches("<circle").count(), 3);
+        assert!(!line_string.to_svg().to_string().contains("debug-vertices"));
+    }
+
+    #[test]
+    fn test_centroid_markers() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)]),
+            vec![],
+        );
+        let line_string = LineString::from(vec![(0.0, 10.0), (6.0, 10.0)]);
+        let marker = Style {
+            fill: Some(Color::Named("red")),
+            ..Style::default()
+        };
+        let svg = polygon
+            .to_svg()
+            .and(line_string.to_svg())
+            .with_centroid_markers(&marker)
+            .to_string();
+        assert!(svg.contains(r#"Z"/><circle cx="2.0" cy="1.0" r="1" fill="red"/>"#));
+        assert!(svg.contains(r#"<circle cx="3.0" cy="10.0" r="1" fill="red"/>"#));
+    }
+
+    #[test]
+    fn test_direction_arrows() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (0.0, 10.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_stroke_color(Color::Named("blue"))
+            .with_direction_arrows(5.0)
+            .to_string();
+        assert!(svg.contains(
+            r#"<path class="direction-arrows" d="M0 3.5L-0.75 1.5L0.75 1.5zM0 8.5L-0.75 6.5L0.75 6.5z" fill="blue" stroke="none"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_segment_lengths() {
+        let line = Line::new((0.0, 0.0), (0.0, 10.0));
+        let svg = line.to_svg().with_segment_lengths("km", 2).to_string();
+        assert!(svg.contains(
+            r#"<text x="0.0" y="5.0" dy="-0.3em" transform="rotate(90 0.0 5.0)">10.00 km</text>"#
+        ));
+    }
+
+    #[test]
+    fn test_area_label() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)]),
+            vec![],
+        );
+        let svg = polygon.to_svg().with_area_label("{area:.1} m²").to_string();
+        assert!(svg.contains(r#"<text x="2.0" y="1.0" text-anchor="middle" dominant-baseline="central">8.0 m²</text>"#));
+        let svg = polygon
+            .to_svg()
+            .with_label("Lot 4")
+            .with_area_label("{area} m²")
+            .to_string();
+        assert!(svg.contains(
+            r#"<tspan x="2.0" dy="-0.6em">Lot 4</tspan><tspan x="2.0" dy="1.2em">8 m²</tspan>"#
+        ));
+    }
+
+    #[cfg(not(feature = "geo"))]
+    #[test]
+    fn test_geodesic_area_without_geo() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)]),
+            vec![],
+        );
+        let style = Style {
+            area_label: Some("{area} m²".into()),
+            geodesic_area: true,
+            ..Style::default()
+        };
+        assert_eq!(polygon.labels(&style)[0].text, "8 m²");
+    }
+
+    #[test]
+    fn test_coordinate_labels() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (0.0, 2.0)]),
+            vec![],
+        );
+        let svg = polygon.to_svg().with_coordinate_labels(0).to_string();
+        assert!(svg.contains(r#"<g class="coordinate-labels""#));
+        assert!(svg.contains(r#">4, 0</text>"#));
+        assert_eq!(svg.matches("</text>").count(), 3);
+    }
+
+    #[test]
+    fn test_normalized_winding() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (0.0, 4.0), (4.0, 0.0)]),
+            vec![],
+        );
+        let svg = polygon.to_svg().with_normalized_winding().to_string();
+        assert!(svg.contains(r#"d="M 0.0 0.0 L 4.0 0.0 L 0.0 4.0 L 0.0 0.0 Z""#));
+        assert_eq!(polygon.exterior().0[1], Coord { x: 0.0, y: 4.0 });
+    }
+
+    #[test]
+    fn test_hole_style() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0, 0), (4, 0), (4, 4), (0, 4)]),
+            vec![LineString::from(vec![(1, 1), (2, 1), (2, 2)])],
+        );
+        let hole_style = Style {
+            stroke_color: Some(Color::Named("red")),
+            ..Style::default()
+        };
+        let svg = polygon
+            .to_svg()
+            .with_fill_color(Color::Named("green"))
+            .with_stroke_color(Color::Named("black"))
+            .with_hole_style(&hole_style)
+            .to_string();
+        assert!(svg.contains(concat!(
+            r#"<path fill-rule="evenodd" d="M 0 0 L 4 0 L 4 4 L 0 4 L 0 0 Z M 1 1 L 2 1 L 2 2 L 1 1 Z" fill="green"/>"#,
+            r#"<path d="M 0 0 L 4 0 L 4 4 L 0 4 L 0 0 Z" fill="none" stroke="black"/>"#,
+            r#"<path d="M 1 1 L 2 1 L 2 2 L 1 1 Z" fill="none" stroke="red"/>"#,
+        )));
+    }
+
+    #[test]
+    fn test_debug_bbox() {
+        let point = Point::new(10.0, 20.0);
+        let line_string = LineString::from(vec![(0.0, 0.0), (4.0, 2.0)]);
+        let svg = point
+            .to_svg()
+            .with_radius(2.0)
+            .and(line_string.to_svg())
+            .with_debug_bbox()
+            .to_string();
+        assert!(svg.contains(r#"<circle cx="10.0" cy="20.0" r="2"/><rect x="7" y="17" width="6" height="6" class="debug-bbox""#));
+        assert!(svg.contains(r#"<rect x="-1" y="-1" width="6" height="4" class="debug-bbox""#));
+    }
+
+    #[test]
+    fn test_snap_grid() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (0.2, 0.1), (10.4, 0.2)]);
+        let svg = line_string.to_svg().with_snap_grid(1.0).to_string();
+        assert!(svg.contains(r#"d="M 0.0 0.0 L 10.0 0.0""#));
+
+        // in the units of the projection
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]),
+            vec![],
+        );
+        let svg = polygon
+            .to_svg()
+            .with_projection(Projection::custom(|coord: Coord<f64>| Coord {
+                x: coord.x * 1000.0 + 0.3,
+                y: coord.y * 1000.0 + 0.4,
+            }))
+            .with_snap_grid(1.0)
+            .to_string();
+        assert!(svg.contains(r#"d="M 0.0 0.0 L 1000.0 0.0 L 0.0 1000.0 L 0.0 0.0 Z""#));
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new((0.0, 10.0), (20.0, 0.0));
+        let svg = rect.to_svg().with_corner_radius(2.0).to_string();
+        assert!(svg.contains(r#"<rect x="0.0" y="0.0" width="20.0" height="10.0" rx="2"/>"#));
+    }
+
+    #[test]
+    fn test_rect_snap_grid() {
+        let rect = Rect::new((0.2, 0.3), (4.4, 2.6));
+        let polygon = Polygon::from(rect);
+        let svg = rect.to_svg().with_snap_grid(1.0).to_string();
+        assert!(!svg.contains("<rect"));
+        assert_eq!(svg, polygon.to_svg().with_snap_grid(1.0).to_string());
+    }
+
+    #[test]
+    fn test_rect_debug_vertices() {
+        let rect = Rect::new((0.2, 0.3), (4.4, 2.6));
+        let polygon = Polygon::from(rect);
+        let svg = rect.to_svg().with_debug_vertices().to_string();
+        assert!(svg.contains("<circle"));
+        assert_eq!(svg, polygon.to_svg().with_debug_vertices().to_string());
+    }
+
+    #[test]
+    fn test_rect_centroid_markers() {
+        let rect = Rect::new((0.2, 0.3), (4.4, 2.6));
+        let polygon = Polygon::from(rect);
+        let marker = Style {
+            fill: Some(Color::Named("red")),
+            ..Style::default()
+        };
+        let svg = rect.to_svg().with_centroid_markers(&marker).to_string();
+        assert!(svg.contains(r#"fill="red""#));
+        assert_eq!(
+            svg,
+            polygon.to_svg().with_centroid_markers(&marker).to_string()
+        );
+    }
+
+    #[test]
+    fn test_rect_coordinate_labels() {
+        let rect = Rect::new((0.2, 0.3), (4.4, 2.6));
+        let polygon = Polygon::from(rect);
+        let svg = rect.to_svg().with_coordinate_labels(0).to_string();
+        assert!(svg.contains("<text"));
+        assert_eq!(svg, polygon.to_svg().with_coordinate_labels(0).to_string());
+    }
+
+    #[test]
+    fn test_rect_wrap_antimeridian() {
+        let rect = Rect::new((170.0, 0.0), (-170.0, 10.0));
+        let polygon = Polygon::from(rect);
+        let svg = rect.to_svg().with_wrap_antimeridian().to_string();
+        assert!(!svg.contains("<rect"));
+        assert_eq!(svg, polygon.to_svg().with_wrap_antimeridian().to_string());
+    }
+
+    #[test]
+    fn test_primitive_elements() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (1.0, 2.0)]);
+        assert!(line_string
+            .to_svg()
+            .with_element_mode(ElementMode::Primitive)
+            .to_string()
+            .contains(r#"<polyline points="0.0,0.0 1.0,2.0"/>"#));
+
+        let polygon = Polygon::new(LineString::from(vec![(0, 0), (4, 0), (0, 4)]), vec![]);
+        assert!(polygon
+            .to_svg()
+            .with_element_mode(ElementMode::Primitive)
+            .to_string()
+            .contains(r#"<polygon points="0,0 4,0 0,4 0,0"/>"#));
+    }
+
+    #[test]
+    fn test_line_string() {
+        let line_string = LineString::from(vec![(0, 0), (10, 0), (10, 10)]);
+        assert!(line_string
+            .to_svg()
+            .to_string()
+            .contains(r#"d="M 0 0 L 10 0 L 10 10""#));
+    }
+
+    #[test]
+    fn test_escaping() {
+        let point = Point::new(0.0, 0.0);
+        let svg = point
+            .to_svg()
+            .with_css_classes(r#"a" onload="alert(1)"#.into())
+            .with_label("<script>alert('x')</script> & co");
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"class="a&quot; onload=&quot;alert(1)""#));
+        assert!(svg.contains(">&lt;script&gt;alert(&apos;x&apos;)&lt;/script&gt; &amp; co</text>"));
+        assert!(!svg.contains("<script>"));
+
+        let line_string = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_id(r#"road"/><g id="x"#.into())
+            .with_text(Some("A & B".into()), None, Some("<big>".into()))
+            .to_string();
+        assert!(svg.contains(r#"id="road&quot;/&gt;&lt;g id=&quot;x""#));
+        assert!(svg.contains(r#"<text class="&lt;big&gt;">"#));
+        assert!(svg.contains(">A &amp; B<"));
+    }
+
+    #[test]
+    fn test_empty_viewbox() {
+        let point = Point::new(10.0, 10.0);
+        let line_string: LineString<f64> = LineString(vec![]);
+        let multi_polygon: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert!(line_string.to_svg().viewbox().is_empty());
+        assert!(multi_polygon.to_svg().viewbox().is_empty());
+        let svg = line_string
+            .to_svg()
+            .and(point.to_svg())
+            .and(multi_polygon.to_svg());
+        assert_eq!(svg.viewbox(), point.to_svg().viewbox());
+
+        // nested documents count with their items
+        let nested = point.to_svg();
+        assert_eq!(
+            ToSvgStr::viewbox(&nested, &Style::default()),
+            point.to_svg().viewbox()
+        );
+    }
+
+    #[test]
+    fn test_large_coordinates_viewbox() {
+        // UTM coordinates which a f32 would round to the nearest half
+        let point = Point::new(500_000.25, 4_649_776.75);
+        let svg = point.to_svg().with_radius(1.0);
+        assert!(svg
+            .to_string()
+            .contains(r#"viewBox="499998.25 4649774.75 4 4""#));
+    }
+
+    #[test]
+    fn test_estimated_len() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.5, 0.5), (100.25, 0.5), (100.25, 80.75), (0.5, 0.5)]),
+            vec![],
+        );
+        let point = Point::new(1.0, 2.0);
+        let svg = polygon.to_svg().and(point.to_svg());
+        let content_len = svg.svg_str().len();
+        assert!(svg.estimated_len() >= content_len);
+        assert!(svg.estimated_len() < content_len * 4);
+    }
+
+    #[test]
+    fn test_clip() {
+        let clip = Rect::new((0.0, 0.0), (5.0, 5.0));
+        let svg = Point::new(1.0, 1.0)
+            .to_svg()
+            .and(Point::new(8.0, 8.0).to_svg())
+            .with_clip(&clip)
+            .to_string();
+        assert!(svg.contains(
+            r#"<defs><clipPath id="clip-0"><rect x="0.0" y="0.0" width="5.0" height="5.0"/></clipPath></defs>"#
+        ));
+        assert!(svg.contains(r#"<g clip-path="url(#clip-0)"><circle cx="1.0""#));
+    }
+
+    #[test]
+    fn test_inset() {
+        let clip = Rect::new((0.0, 0.0), (5.0, 5.0));
+        let location = Point::new(1.0, 1.0);
+        let overview = location.to_svg().with_clip(&clip);
+        let svg = Point::new(50.0, 50.0)
+            .to_svg()
+            .with_clip(&clip)
+            .inset(overview, 80.0, 80.0, 20.0, 20.0)
+            .to_string();
+        assert!(svg.contains(r#"<clipPath id="clip-0">"#));
+        assert!(svg.contains(
+            r#"<svg x="80" y="80" width="20" height="20" preserveAspectRatio="xMidYMid meet" viewBox="-1 -1 4 4"><g clip-path="url(#clip-1)"><circle cx="1.0""#
+        ));
+    }
+
+    #[test]
+    fn test_mask() {
+        let focus = Rect::new((0.0, 0.0), (5.0, 5.0));
+        let svg = Point::new(1.0, 1.0)
+            .to_svg()
+            .with_mask(focus.to_svg().with_fill_color(Color::Named("white")))
+            .to_string();
+        assert!(svg.contains(
+            r#"<defs><mask id="mask-0"><rect x="0.0" y="0.0" width="5.0" height="5.0" fill="white"/></mask></defs>"#
+        ));
+        assert!(svg.contains(r#"<g mask="url(#mask-0)"><circle cx="1.0""#));
+    }
+
+    #[test]
+    fn test_gradient_mask() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 5.0));
+        let fade = vec![(0.0, Color::Named("white")), (1.0, Color::Named("black"))];
+        let svg = rect
+            .to_svg()
+            .with_gradient_mask(Gradient::Linear {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 1.0,
+                y2: 0.0,
+                stops: fade.clone(),
+            })
+            .to_string();
+        assert!(svg.contains(
+            r#"<defs><linearGradient id="gradient-0" x1="0" y1="0" x2="1" y2="0"><stop offset="0" stop-color="white"/><stop offset="1" stop-color="black"/></linearGradient><mask id="mask-0" maskContentUnits="objectBoundingBox"><rect width="1" height="1" fill="url(#gradient-0)"/></mask></defs>"#
+        ));
+        assert!(svg.contains(r#"<g mask="url(#mask-0)"><rect x="0.0""#));
+
+        let svg = rect
+            .to_svg()
+            .with_gradient_mask(Gradient::Radial {
+                cx: 0.5,
+                cy: 0.5,
+                r: 0.5,
+                stops: fade,
+            })
+            .to_string();
+        assert!(svg.contains(r#"<radialGradient id="gradient-0" cx="0.5" cy="0.5" r="0.5"><stop offset="0" stop-color="white"/>"#));
+    }
+
+    #[test]
+    fn test_filter() {
+        let svg = Point::new(1.0, 1.0)
+            .to_svg()
+            .and(Point::new(2.0, 2.0).to_svg())
+            .with_filter(Filter::GaussianBlur(0.5))
+            .to_string();
+        assert!(svg.contains(
+            r#"<defs><filter id="filter-0"><feGaussianBlur stdDeviation="0.5"/></filter></defs>"#
+        ));
+        assert_eq!(svg.matches(r#"<g filter="url(#filter-0)">"#).count(), 2);
+    }
+
+    #[test]
+    fn test_background() {
+        let svg = Rect::new((0.0, 0.0), (4.0, 2.0))
+            .to_svg()
+            .with_background(Color::Named("white"))
+            .to_string();
+        assert!(svg.contains(
+            r#"viewBox="-1 -1 6 4"><rect x="-1" y="-1" width="6" height="4" fill="white"/><rect x="0.0""#
+        ));
+    }
+
+    #[test]
+    fn test_frame() {
+        let svg = Rect::new((0.0, 0.0), (4.0, 2.0))
+            .to_svg()
+            .with_frame(Color::Named("black"), 0.5)
+            .to_string();
+        assert!(svg.ends_with(
+            r#"<rect x="-0.75" y="-0.75" width="5.5" height="3.5" fill="none" stroke="black" stroke-width="0.5"/></svg>"#
+        ));
+    }
+
+    #[test]
+    fn test_collect_svgs() {
+        let points: Vec<_> = (0..3).map(|x| Point::new(x as f64, 0.0)).collect();
+        let rect = Rect::new((0.0, 1.0), (1.0, 2.0));
+        let mut svg: Svg = points.iter().map(|point| point.to_svg()).collect();
+        svg.extend(core::iter::once(rect.to_svg()));
+        assert_eq!(svg.siblings.len(), 4);
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"viewBox="-2 -2 6 5"><circle cx="0.0""#));
+        assert!(svg.contains(r#"<circle cx="2.0" cy="0.0" r="1"/><rect"#));
+    }
+
+    #[test]
+    fn test_projection() {
+        let svg = Point::new(180.0, 0.0)
+            .to_svg()
+            .with_projection(Projection::WebMercator)
+            .to_string();
+        assert!(svg.contains(r#"<circle cx="20037508.34278924" cy="0.0" r="1"/>"#));
+
+        // rotated by 45°, the rectangle becomes a diamond
+        let svg = Rect::new((0.0, 0.0), (2.0, 1.0))
+            .to_svg()
+            .with_projection(Projection::custom(|coord: Coord<f64>| Coord {
+                x: coord.x - coord.y,
+                y: coord.x + coord.y,
+            }))
+            .to_string();
+        assert!(svg.contains(r#"d="M 0.0 0.0 L 2.0 2.0 L 1.0 3.0 L -1.0 1.0 L 0.0 0.0 Z""#));
+    }
+
+    #[test]
+    fn test_swap_axes() {
+        let line_string = LineString::from(vec![(1.0, 2.0), (3.0, 4.0)]);
+        let svg = line_string.to_svg().with_swap_axes().to_string();
+        assert!(svg.contains(r#"viewBox="1 0 4 4"><path d="M 2.0 1.0 L 4.0 3.0""#));
+
+        // swapped before the projection, which gets the longitude first
+        let point = Point::new(0.0, 180.0);
+        let svg = point
+            .to_svg()
+            .with_swap_axes()
+            .with_projection(Projection::WebMercator)
+            .to_string();
+        assert!(svg.contains(r#"<circle cx="20037508.34278924" cy="0.0" r="1"/>"#));
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let polygon = Polygon::new(LineString::from(vec![(0, 0), (4, 0), (0, 4)]), vec![]);
+        assert!(polygon
+            .to_svg()
+            .with_path_encoding(PathEncoding::Relative)
+            .to_string()
+            .contains(r#"d="M 0 0 l 4 0 l -4 4 l 0 -4 z""#));
+    }
+
     #[test]
     fn test_polygon() {
         println!(
@@ -0,0 +1,183 @@
+use crate::Svg;
+use ::svg::node::element::tag::Type;
+use ::svg::node::element::{
+    Circle, ClipPath, Definitions, Element, Ellipse, Filter, FilterEffectGaussianBlur, Group,
+    Image, Line, LinearGradient, Mask, Path, Polygon, Polyline, RadialGradient, Rectangle, Stop,
+    Symbol, TSpan, Text, TextPath, Title, Use,
+};
+use ::svg::node::{Node, Text as TextNode};
+use ::svg::parser::Event;
+use ::svg::{Document, Parser};
+
+/// Text of the markup with the entities written by [`Escaped`](crate::escape::Escaped)
+/// replaced by their characters.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Moves the attributes and children of `element` to `node`.
+fn fill<N: Node>(mut node: N, mut element: Element) -> N {
+    // elements of text have a first child with their content
+    if let Some(children) = node.get_children_mut() {
+        children.clear();
+    }
+    for (name, value) in element.get_attributes_mut().drain() {
+        node.assign(name, unescape(&value));
+    }
+    for child in element.get_children_mut().drain(..) {
+        node.append(child);
+    }
+    node
+}
+
+/// The element as the node type of the `svg` crate for its name, e.g. a [`Path`] for
+/// `<path>`, or as is for other elements.
+fn typed(element: Element) -> Box<dyn Node> {
+    match element.get_name() {
+        "circle" => Box::new(fill(Circle::new(), element)),
+        "clipPath" => Box::new(fill(ClipPath::new(), element)),
+        "defs" => Box::new(fill(Definitions::new(), element)),
+        "ellipse" => Box::new(fill(Ellipse::new(), element)),
+        "feGaussianBlur" => Box::new(fill(FilterEffectGaussianBlur::new(), element)),
+        "filter" => Box::new(fill(Filter::new(), element)),
+        "g" => Box::new(fill(Group::new(), element)),
+        "image" => Box::new(fill(Image::new(), element)),
+        "line" => Box::new(fill(Line::new(), element)),
+        "linearGradient" => Box::new(fill(LinearGradient::new(), element)),
+        "mask" => Box::new(fill(Mask::new(), element)),
+        "path" => Box::new(fill(Path::new(), element)),
+        "polygon" => Box::new(fill(Polygon::new(), element)),
+        "polyline" => Box::new(fill(Polyline::new(), element)),
+        "radialGradient" => Box::new(fill(RadialGradient::new(), element)),
+        "rect" => Box::new(fill(Rectangle::new(), element)),
+        "stop" => Box::new(fill(Stop::new(), element)),
+        "symbol" => Box::new(fill(Symbol::new(), element)),
+        "text" => Box::new(fill(Text::new(""), element)),
+        "textPath" => Box::new(fill(TextPath::new(""), element)),
+        "title" => Box::new(fill(Title::new(""), element)),
+        "tspan" => Box::new(fill(TSpan::new(""), element)),
+        "use" => Box::new(fill(Use::new(), element)),
+        _ => {
+            let name = element.get_name().to_string();
+            Box::new(fill(Element::new(name), element))
+        }
+    }
+}
+
+/// Nodes of the elements of `markup`, which is well formed since it's rendered by the crate.
+fn nodes(markup: &str) -> Vec<Box<dyn Node>> {
+    // the root collects the top level nodes
+    let mut open = vec![Element::new("")];
+    for event in Parser::new(markup) {
+        match event {
+            Event::Tag(name, Type::Start, attributes) => {
+                let mut element = Element::new(name);
+                *element.get_attributes_mut() = attributes;
+                open.push(element);
+            }
+            Event::Tag(name, Type::Empty, attributes) => {
+                let mut element = Element::new(name);
+                *element.get_attributes_mut() = attributes;
+                if let Some(parent) = open.last_mut() {
+                    parent.append(typed(element));
+                }
+            }
+            Event::Tag(_, Type::End, _) if open.len() > 1 => {
+                if let Some(element) = open.pop() {
+                    if let Some(parent) = open.last_mut() {
+                        parent.append(typed(element));
+                    }
+                }
+            }
+            Event::Text(text) => {
+                if let Some(parent) = open.last_mut() {
+                    parent.append(TextNode::new(unescape(text)));
+                }
+            }
+            _ => {}
+        }
+    }
+    open.swap_remove(0).get_children_mut().drain(..).collect()
+}
+
+impl Svg<'_> {
+    /// Group holding the rendered geometries and their definitions as nodes of the `svg`
+    /// crate, e.g. [`Path`] and [`Circle`] elements, to be added to a document built with it
+    /// and inspected or restyled there. The nodes are read back from the rendered markup, so
+    /// they cost a parse of the output.
+    pub fn to_svg_node(&self) -> Group {
+        nodes(&self.document_body())
+            .into_iter()
+            .fold(Group::new(), Group::add)
+    }
+
+    /// Document of the `svg` crate equivalent to the [`Display`](std::fmt::Display) output,
+    /// with the same attributes on its root.
+    pub fn to_svg_document(&self) -> Document {
+        let mut document = Document::new();
+        for mut svg in nodes(&self.to_string()) {
+            if let Some(attributes) = svg.get_attributes_mut() {
+                for (name, value) in attributes.drain() {
+                    document.assign(name, value);
+                }
+            }
+            if let Some(children) = svg.get_children_mut() {
+                for child in children.drain(..) {
+                    document.append(child);
+                }
+            }
+        }
+        document
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, ToSvg};
+    use ::svg::node::element::Group;
+    use ::svg::node::Node;
+    use ::svg::Document;
+    use geo_types::{LineString, Point};
+
+    #[test]
+    fn test_svg_node() {
+        let point = Point::new(1.0, 2.0);
+        let document = Document::new().add(
+            Group::new()
+                .set("id", "layer")
+                .add(point.to_svg().to_svg_node()),
+        );
+        assert!(document
+            .to_string()
+            .contains(r#"<circle cx="1.0" cy="2.0" r="1"/>"#));
+        assert!(point
+            .to_svg()
+            .to_svg_document()
+            .to_string()
+            .contains(r#"viewBox="-1 0 4 4""#));
+    }
+
+    #[test]
+    fn test_svg_node_structure() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_id("line".into())
+            .with_stroke_color(Color::Named("red"))
+            .with_text(Some("A & B".into()), None, None);
+        let mut group = svg.to_svg_node();
+        let children = group.get_children_mut().unwrap();
+        assert_eq!(children[0].get_name(), "path");
+        let attributes = children[0].get_attributes_mut().unwrap();
+        assert_eq!(attributes["stroke"].to_string(), "red");
+        attributes.insert("stroke".into(), "blue".into());
+        assert_eq!(children[1].get_name(), "text");
+        let group = group.to_string();
+        assert!(group.contains(r#"stroke="blue""#));
+        assert!(group.contains("A &amp; B</textPath>"));
+    }
+}
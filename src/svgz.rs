@@ -0,0 +1,40 @@
+use crate::Svg;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::Write;
+
+impl Svg<'_> {
+    /// Renders the document gzip-compressed, as served for `.svgz` files or with a
+    /// `Content-Encoding: gzip` header.
+    ///
+    /// To compress on the fly instead, pass a [`GzEncoder`] to [`render_iter`].
+    ///
+    /// [`render_iter`]: crate::render_iter
+    pub fn to_svgz(&self) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        write!(encoder, "{}", self).expect("writing to a Vec doesn't fail");
+        encoder.finish().expect("writing to a Vec doesn't fail")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSvg;
+    use flate2::read::GzDecoder;
+    use geo_types::LineString;
+    use std::io::Read;
+
+    #[test]
+    fn test_to_svgz() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 10.0), (20.0, 0.0)]);
+        let svg = line_string.to_svg();
+        let svgz = svg.to_svgz();
+        assert_eq!(&svgz[..2], &[0x1f, 0x8b]);
+
+        let mut document = String::new();
+        GzDecoder::new(&svgz[..])
+            .read_to_string(&mut document)
+            .unwrap();
+        assert_eq!(document, svg.to_string());
+    }
+}
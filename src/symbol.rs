@@ -0,0 +1,105 @@
+use crate::escape::Escaped;
+use alloc::collections::BTreeMap;
+use alloc::{format, string::String};
+use core::fmt::{Display, Formatter, Result};
+
+/// A reusable point marker drawn from arbitrary SVG path data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub path: String,
+    pub viewbox: (f32, f32, f32, f32),
+    /// Point of the symbol's viewbox that is placed on the coordinate.
+    pub anchor: (f32, f32),
+}
+
+impl Symbol {
+    /// Creates a symbol anchored at the center of its viewbox.
+    pub fn new(path: &str, viewbox: (f32, f32, f32, f32)) -> Self {
+        let (min_x, min_y, width, height) = viewbox;
+        Self {
+            path: path.into(),
+            viewbox,
+            anchor: (min_x + width / 2.0, min_y + height / 2.0),
+        }
+    }
+
+    pub fn with_anchor(mut self, x: f32, y: f32) -> Self {
+        self.anchor = (x, y);
+        self
+    }
+
+    /// Square viewbox centered on the anchor and just containing the symbol, so that a point
+    /// of radius `r` draws the symbol within `r` of its coordinate, anchor on the coordinate.
+    fn anchored_viewbox(&self) -> (f32, f32, f32, f32) {
+        let (min_x, min_y, width, height) = self.viewbox;
+        let (x, y) = self.anchor;
+        let half_size = (x - min_x)
+            .abs()
+            .max((min_x + width - x).abs())
+            .max((y - min_y).abs())
+            .max((min_y + height - y).abs());
+        (
+            x - half_size,
+            y - half_size,
+            half_size * 2.0,
+            half_size * 2.0,
+        )
+    }
+}
+
+/// Named symbols, emitted once in the document `<defs>` and referenced by points through
+/// [`Svg::with_symbol`](crate::Svg::with_symbol).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolRegistry {
+    symbols: BTreeMap<String, Symbol>,
+}
+
+impl SymbolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, name: &str, symbol: Symbol) -> &mut Self {
+        self.symbols.insert(name.into(), symbol);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub(crate) fn extend(&mut self, other: &SymbolRegistry) {
+        for (name, symbol) in &other.symbols {
+            self.symbols
+                .entry(name.clone())
+                .or_insert_with(|| symbol.clone());
+        }
+    }
+}
+
+pub(crate) fn symbol_id(name: &str) -> String {
+    format!("symbol-{}", Escaped(name))
+}
+
+impl Display for SymbolRegistry {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        for (name, symbol) in &self.symbols {
+            let (min_x, min_y, width, height) = symbol.anchored_viewbox();
+            write!(
+                fmt,
+                r#"<symbol id="{id}" viewBox="{min_x} {min_y} {width} {height}"><path d="{path}"/></symbol>"#,
+                id = symbol_id(name),
+                min_x = min_x,
+                min_y = min_y,
+                width = width,
+                height = height,
+                path = Escaped(&symbol.path),
+            )?;
+        }
+        Ok(())
+    }
+}
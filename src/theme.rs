@@ -0,0 +1,108 @@
+use crate::{Color, Svg};
+
+/// Preset look of a whole map, applied with [`Svg::with_theme`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Theme {
+    /// Dark geometries on a white background.
+    Light,
+    /// Light geometries on a dark background.
+    Dark,
+    /// Black outlines and light gray fills for printing, with a frame.
+    Print,
+    /// White strokes on blueprint blue, without fills.
+    Blueprint,
+}
+
+impl Theme {
+    pub fn background(&self) -> Color {
+        match self {
+            Theme::Light | Theme::Print => Color::Named("white"),
+            Theme::Dark => Color::Hex(0x1E1E1E),
+            Theme::Blueprint => Color::Hex(0x1F4E8C),
+        }
+    }
+
+    pub fn fill(&self) -> Color {
+        match self {
+            Theme::Light => Color::Hex(0xDDE5ED),
+            Theme::Dark => Color::Hex(0x3A4A5A),
+            Theme::Print => Color::Hex(0xEEEEEE),
+            Theme::Blueprint => Color::Named("none"),
+        }
+    }
+
+    pub fn stroke(&self) -> Color {
+        match self {
+            Theme::Light => Color::Hex(0x333333),
+            Theme::Dark => Color::Hex(0xD0D0D0),
+            Theme::Print => Color::Named("black"),
+            Theme::Blueprint => Color::Named("white"),
+        }
+    }
+
+    pub fn font_family(&self) -> &'static str {
+        match self {
+            Theme::Light | Theme::Dark => "sans-serif",
+            Theme::Print => "serif",
+            Theme::Blueprint => "monospace",
+        }
+    }
+
+    /// Categorical colors for data driven styling, readable on the background.
+    pub fn palette(&self) -> &'static [Color] {
+        match self {
+            Theme::Light | Theme::Print => &[
+                Color::Hex(0x1F77B4),
+                Color::Hex(0xFF7F0E),
+                Color::Hex(0x2CA02C),
+                Color::Hex(0xD62728),
+                Color::Hex(0x9467BD),
+                Color::Hex(0x8C564B),
+            ],
+            Theme::Dark | Theme::Blueprint => &[
+                Color::Hex(0x8DD3C7),
+                Color::Hex(0xFFFFB3),
+                Color::Hex(0xBEBADA),
+                Color::Hex(0xFB8072),
+                Color::Hex(0x80B1D3),
+                Color::Hex(0xFDB462),
+            ],
+        }
+    }
+}
+
+impl<'a> Svg<'a> {
+    /// Sets the background, the fill and stroke colors and the font of the theme in one call,
+    /// text getting a halo of the background color. Later style calls override them.
+    pub fn with_theme(self, theme: Theme) -> Self {
+        let svg = self
+            .with_background(theme.background())
+            .with_fill_color(theme.fill())
+            .with_stroke_color(theme.stroke())
+            .with_font_family(theme.font_family())
+            .with_text_halo(theme.background(), 2.0);
+        match theme {
+            Theme::Print => svg.with_frame(theme.stroke(), 1.0),
+            _ => svg,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+    use crate::ToSvg;
+    use geo_types::{Point, Rect};
+
+    #[test]
+    fn test_theme() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let point = Point::new(5.0, 5.0);
+        let svg = rect.to_svg().and(point.to_svg()).with_theme(Theme::Dark);
+        let svg = svg.to_string();
+        assert!(svg.contains(r##"fill="#1E1E1E""##));
+        assert!(
+            svg.contains(r##"<circle cx="5.0" cy="5.0" r="1" fill="#3A4A5A" stroke="#D0D0D0"/>"##)
+        );
+    }
+}
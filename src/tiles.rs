@@ -0,0 +1,140 @@
+use crate::{Projection, Svg, ViewBox};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::f64::consts::PI;
+use core::ops::RangeInclusive;
+use geo_types::Coord;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// Side of a tile in svg user units.
+pub const TILE_SIZE: f64 = 256.0;
+
+/// Position of a tile in the XYZ (slippy map) scheme, `y` growing southwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    /// Web Mercator projection of longitudes and latitudes, in degrees, to coordinates in this
+    /// tile, in `0.0..TILE_SIZE` inside it.
+    pub fn projection(&self) -> Projection {
+        let tile = *self;
+        Projection::custom(move |coord: Coord<f64>| {
+            let (x, y) = world_position(coord, tile.z);
+            Coord {
+                x: x - tile.x as f64 * TILE_SIZE,
+                y: y - tile.y as f64 * TILE_SIZE,
+            }
+        })
+    }
+}
+
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Position of the coordinate on the whole map at zoom level `z`.
+fn world_position(coord: Coord<f64>, z: u8) -> (f64, f64) {
+    let size = TILE_SIZE * 2f64.powi(z as i32);
+    let latitude = coord.y.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    (
+        (coord.x + 180.0) / 360.0 * size,
+        (1.0 - latitude.tan().asinh() / PI) / 2.0 * size,
+    )
+}
+
+fn intersects(a: &ViewBox, b: &ViewBox) -> bool {
+    !a.is_empty()
+        && a.min_x() <= b.max_x()
+        && a.max_x() >= b.min_x()
+        && a.min_y() <= b.max_y()
+        && a.max_y() >= b.min_y()
+}
+
+/// Copy of the projected svg without the items lying outside of `bounds`.
+fn tile_layer<'a>(svg: &Svg<'a>, bounds: &ViewBox) -> Svg<'a> {
+    let mut layer = svg.clone();
+    layer
+        .items
+        .retain(|item| intersects(&item.viewbox(&svg.style), bounds));
+    layer.siblings = svg
+        .siblings
+        .iter()
+        .map(|sibling| tile_layer(sibling, bounds))
+        .collect();
+    layer
+}
+
+/// Renders the svg, whose coordinates are longitudes and latitudes in degrees, into one
+/// document per tile it covers at each zoom level of `zooms`. Style lengths, such as stroke
+/// widths, are in tile pixels. Geometries outside of a tile are left out of it, the others
+/// are cut by the viewbox of the tile.
+pub fn render_tiles(svg: &Svg, zooms: RangeInclusive<u8>) -> Vec<(Tile, String)> {
+    let extent = svg.viewbox();
+    if extent.is_empty() {
+        return vec![];
+    }
+    let tile_bounds = ViewBox::new(0.0, 0.0, TILE_SIZE, TILE_SIZE);
+    let mut tiles = Vec::new();
+    for z in zooms {
+        let max_index = 2f64.powi(z as i32) - 1.0;
+        let tile_index =
+            |position: f64| (position / TILE_SIZE).floor().clamp(0.0, max_index) as u32;
+        let top_left = Coord {
+            x: extent.min_x(),
+            y: extent.max_y(),
+        };
+        let bottom_right = Coord {
+            x: extent.max_x(),
+            y: extent.min_y(),
+        };
+        let (min_x, min_y) = world_position(top_left, z);
+        let (max_x, max_y) = world_position(bottom_right, z);
+        for y in tile_index(min_y)..=tile_index(max_y) {
+            for x in tile_index(min_x)..=tile_index(max_x) {
+                let tile = Tile { z, x, y };
+                let mut layer = tile_layer(
+                    &svg.clone().with_projection(tile.projection()),
+                    &tile_bounds,
+                );
+                layer.custom_viewbox = Some(tile_bounds);
+                tiles.push((tile, layer.to_string()));
+            }
+        }
+    }
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_tiles, Tile};
+    use crate::ToSvg;
+    use geo_types::{LineString, Point};
+
+    #[test]
+    fn test_render_tiles() {
+        let point = Point::new(90.0, 45.0);
+        let line_string = LineString::from(vec![(-100.0, -10.0), (-90.0, -20.0)]);
+        let svg = point.to_svg().and(line_string.to_svg());
+        let tiles = render_tiles(&svg, 0..=1);
+        let ids: Vec<_> = tiles.iter().map(|(tile, _)| *tile).collect();
+        assert_eq!(
+            ids,
+            vec![
+                Tile { z: 0, x: 0, y: 0 },
+                Tile { z: 1, x: 0, y: 0 },
+                Tile { z: 1, x: 1, y: 0 },
+                Tile { z: 1, x: 0, y: 1 },
+                Tile { z: 1, x: 1, y: 1 },
+            ]
+        );
+        let (_, north_east) = &tiles[2];
+        assert!(north_east.contains(r#"viewBox="0 0 256 256"><circle cx="128.0""#));
+        assert!(!north_east.contains("<path"));
+    }
+}
@@ -1,17 +1,232 @@
-use crate::{Style, Svg, ToSvgStr, ViewBox};
+use crate::{Style, Svg, ToSvgStr};
+use alloc::{vec, vec::Vec};
+use core::fmt::{Display, Formatter};
+use geo_types::{
+    CoordNum, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
 
 pub trait ToSvg {
-    fn to_svg(&self) -> Svg;
+    fn to_svg(&self) -> Svg<'_>;
 }
 
 impl<T: ToSvgStr> ToSvg for T {
-    fn to_svg(&self) -> Svg {
-        Svg {
-            items: vec![self],
-            siblings: vec![],
-            viewbox: ViewBox::default(),
-            custom_viewbox: None,
-            style: Style::default(),
+    fn to_svg(&self) -> Svg<'_> {
+        Svg::new(vec![self])
+    }
+}
+
+/// Collections whose members can each get their own style.
+pub trait ToSvgWith {
+    type Member;
+
+    /// Converts each member with the style returned by `style` for its index and itself, e.g.
+    /// to color features from their attributes.
+    fn to_svg_with<F>(&self, style: F) -> Svg<'_>
+    where
+        F: FnMut(usize, &Self::Member) -> Style;
+
+    /// Converts each member into its own svg, to be styled or combined separately, e.g. to
+    /// emphasize the largest part of a multi polygon.
+    fn to_svg_parts(&self) -> Vec<Svg<'_>>;
+
+    /// Converts each member with the style at the same index, e.g. styles chosen by a parser
+    /// for each geometry it read. Fails unless there are as many styles as members.
+    fn to_svg_with_styles(&self, styles: &[Style]) -> Result<Svg<'_>, StyleCountError> {
+        let parts = self.to_svg_parts();
+        if parts.len() != styles.len() {
+            return Err(StyleCountError {
+                members: parts.len(),
+                styles: styles.len(),
+            });
         }
+        Ok(parts
+            .into_iter()
+            .zip(styles)
+            .map(|(part, style)| part.with_style(style))
+            .collect())
+    }
+}
+
+/// The number of styles given to [`ToSvgWith::to_svg_with_styles`] isn't the number of
+/// members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleCountError {
+    pub members: usize,
+    pub styles: usize,
+}
+
+impl Display for StyleCountError {
+    fn fmt(&self, fmt: &mut Formatter) -> core::fmt::Result {
+        write!(
+            fmt,
+            "{} styles given for {} members",
+            self.styles, self.members
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StyleCountError {}
+
+fn styled<'a, T, F>(members: impl Iterator<Item = &'a T>, mut style: F) -> Svg<'a>
+where
+    T: ToSvgStr + 'a,
+    F: FnMut(usize, &T) -> Style,
+{
+    members
+        .enumerate()
+        .map(|(index, member)| member.to_svg().with_style(&style(index, member)))
+        .collect()
+}
+
+fn parts<'a, T: ToSvgStr + 'a>(members: impl Iterator<Item = &'a T>) -> Vec<Svg<'a>> {
+    members.map(ToSvg::to_svg).collect()
+}
+
+impl<T: ToSvgStr> ToSvgWith for [T] {
+    type Member = T;
+
+    fn to_svg_with<F: FnMut(usize, &T) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+impl<T: ToSvgStr> ToSvgWith for Vec<T> {
+    type Member = T;
+
+    fn to_svg_with<F: FnMut(usize, &T) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+impl<T: CoordNum> ToSvgWith for MultiPolygon<T> {
+    type Member = Polygon<T>;
+
+    fn to_svg_with<F: FnMut(usize, &Polygon<T>) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+impl<T: CoordNum> ToSvgWith for MultiLineString<T> {
+    type Member = LineString<T>;
+
+    fn to_svg_with<F: FnMut(usize, &LineString<T>) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+impl<T: CoordNum> ToSvgWith for MultiPoint<T> {
+    type Member = Point<T>;
+
+    fn to_svg_with<F: FnMut(usize, &Point<T>) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+impl<T: CoordNum> ToSvgWith for GeometryCollection<T> {
+    type Member = Geometry<T>;
+
+    fn to_svg_with<F: FnMut(usize, &Geometry<T>) -> Style>(&self, style: F) -> Svg<'_> {
+        styled(self.iter(), style)
+    }
+
+    fn to_svg_parts(&self) -> Vec<Svg<'_>> {
+        parts(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StyleCountError, ToSvgWith};
+    use crate::{Color, Style, Svg};
+    use geo_types::{
+        Geometry, GeometryCollection, LineString, MultiLineString, MultiPolygon, Point, Rect,
+    };
+
+    #[test]
+    fn test_to_svg_with() {
+        let squares = MultiPolygon(vec![
+            Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon(),
+            Rect::new((2.0, 0.0), (3.0, 1.0)).to_polygon(),
+        ]);
+        let colors = [Color::Named("red"), Color::Named("blue")];
+        let svg = squares.to_svg_with(|index, _| Style {
+            fill: Some(colors[index]),
+            ..Style::default()
+        });
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"viewBox="-1 -1 5 3""#));
+        assert!(svg.contains(r#"Z" fill="red"/>"#));
+        assert!(svg.contains(r#"Z" fill="blue"/>"#));
+    }
+
+    #[test]
+    fn test_to_svg_with_styles() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(0.0, 0.0)),
+            Geometry::LineString(LineString::from(vec![(0.0, 0.0), (1.0, 1.0)])),
+        ]);
+        let styles = [
+            Style {
+                fill: Some(Color::Named("red")),
+                ..Style::default()
+            },
+            Style {
+                stroke_color: Some(Color::Named("blue")),
+                ..Style::default()
+            },
+        ];
+        let svg = collection.to_svg_with_styles(&styles).unwrap().to_string();
+        assert!(svg.contains(r#"<circle cx="0.0" cy="0.0" r="1" fill="red"/>"#));
+        assert!(svg.contains(r#"<path d="M 0.0 0.0 L 1.0 1.0" stroke="blue"/>"#));
+        assert_eq!(
+            collection.to_svg_with_styles(&styles[..1]).err(),
+            Some(StyleCountError {
+                members: 2,
+                styles: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_svg_parts() {
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0.0, 0.0), (1.0, 0.0)]),
+            LineString::from(vec![(0.0, 1.0), (5.0, 1.0)]),
+        ]);
+        let longest = 1;
+        let svg: Svg = lines
+            .to_svg_parts()
+            .into_iter()
+            .enumerate()
+            .map(|(index, part)| {
+                let width = if index == longest { 2.0 } else { 0.5 };
+                part.with_stroke_width(width)
+            })
+            .collect();
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"<path d="M 0.0 0.0 L 1.0 0.0" stroke-width="0.5"/>"#));
+        assert!(svg.contains(r#"<path d="M 0.0 1.0 L 5.0 1.0" stroke-width="2"/>"#));
     }
 }
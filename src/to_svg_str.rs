@@ -1,8 +1,56 @@
-use crate::{Style, Svg, ViewBox};
+use crate::checked::element_ids;
+use crate::{Label, Style, Svg, ViewBox};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Bytes assumed for the tag and attributes of an element when estimating output sizes.
+pub(crate) const ELEMENT_LEN: usize = 96;
+
+/// Bytes assumed for a coordinate pair in paths and point lists.
+pub(crate) const COORD_LEN: usize = 24;
 
 pub trait ToSvgStr {
     fn to_svg_str(&self, style: &Style) -> String;
     fn viewbox(&self, style: &Style) -> ViewBox;
+
+    fn labels(&self, _style: &Style) -> Vec<Label> {
+        vec![]
+    }
+
+    /// Rough length of [`ToSvgStr::to_svg_str`], from which output buffers are allocated once
+    /// instead of growing element by element.
+    fn estimated_len(&self) -> usize {
+        ELEMENT_LEN
+    }
+
+    /// Whether all the coordinates are finite numbers, i.e. neither NaN nor infinite, see
+    /// [`Svg::try_to_string`].
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Problems of the geometry that would make it render unexpectedly, see
+    /// [`Svg::to_string_with_warnings`].
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<crate::GeometryWarning> {
+        vec![]
+    }
+
+    /// Whether [`ToSvgStr::to_svg_str`] writes `xlink:href` attributes, for which the
+    /// document declares the xlink namespace. Geometries drawn with other styles than `style`
+    /// tell from their own.
+    fn uses_xlink(&self, style: &Style) -> bool {
+        style.uses_xlink()
+    }
+
+    /// Ids that [`ToSvgStr::to_svg_str`] gives to elements, once per element, to check that
+    /// they're unique. Geometries drawn with other styles than `style` tell from their own.
+    fn ids(&self, style: &Style) -> Vec<String> {
+        style.id.iter().cloned().collect()
+    }
 }
 
 impl<'a> ToSvgStr for Svg<'a> {
@@ -11,6 +59,37 @@ impl<'a> ToSvgStr for Svg<'a> {
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
-        self.clone().with_style(style).viewbox
+        self.clone().with_style(style).viewbox()
+    }
+
+    fn estimated_len(&self) -> usize {
+        let items: usize = self.items.iter().map(|item| item.estimated_len()).sum();
+        items
+            + self
+                .siblings
+                .iter()
+                .map(ToSvgStr::estimated_len)
+                .sum::<usize>()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.items.iter().all(|item| item.is_finite()) && self.siblings.iter().all(Svg::is_finite)
+    }
+
+    fn uses_xlink(&self, style: &Style) -> bool {
+        self.clone().with_style(style).namespaces().xlink
+    }
+
+    fn ids(&self, style: &Style) -> Vec<String> {
+        element_ids(&self.clone().with_style(style))
+    }
+
+    #[cfg(feature = "validation")]
+    fn warnings(&self) -> Vec<crate::GeometryWarning> {
+        self.items
+            .iter()
+            .flat_map(|item| item.warnings())
+            .chain(self.siblings.iter().flat_map(ToSvgStr::warnings))
+            .collect()
     }
 }
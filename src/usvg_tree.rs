@@ -0,0 +1,57 @@
+use crate::Svg;
+use std::sync::{Arc, OnceLock};
+
+/// System fonts available to the text, loaded on the first parse and shared by the later
+/// ones.
+fn system_fonts() -> Arc<usvg::fontdb::Database> {
+    static FONTS: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+    FONTS
+        .get_or_init(|| {
+            let mut fonts = usvg::fontdb::Database::new();
+            fonts.load_system_fonts();
+            Arc::new(fonts)
+        })
+        .clone()
+}
+
+/// Parses the document with the system fonts available to the text.
+pub(crate) fn parse_tree(document: &str) -> Result<usvg::Tree, usvg::Error> {
+    let options = usvg::Options {
+        fontdb: system_fonts(),
+        ..usvg::Options::default()
+    };
+    usvg::Tree::from_str(document, &options)
+}
+
+impl Svg<'_> {
+    /// The document as a usvg tree, ready for resvg based processing such as bounds queries,
+    /// hit testing or rasterization.
+    ///
+    /// usvg only builds trees from markup, so this still renders the document and parses it
+    /// back. The system fonts are loaded once and shared by the trees.
+    pub fn to_usvg_tree(&self) -> Result<usvg::Tree, usvg::Error> {
+        parse_tree(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSvg;
+    use geo_types::Rect;
+
+    #[test]
+    fn test_usvg_tree() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 20.0));
+        let tree = rect.to_svg().to_usvg_tree().unwrap();
+        let bounds = tree.root().abs_bounding_box();
+        assert_eq!((bounds.width(), bounds.height()), (10.0, 20.0));
+    }
+
+    #[test]
+    fn test_shared_fonts() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 20.0));
+        let first = rect.to_svg().to_usvg_tree().unwrap();
+        let second = rect.to_svg().to_usvg_tree().unwrap();
+        assert!(std::sync::Arc::ptr_eq(first.fontdb(), second.fontdb()));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Svg, ToSvgStr};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result};
+use geo_types::{CoordNum, LineString, Polygon};
+use num_traits::NumCast;
+
+/// Geometry that renders, but probably not as intended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryWarning {
+    /// The vertex at `index` repeats the previous one. `ring` is 0 for line strings and
+    /// exterior rings, holes are numbered from 1.
+    DuplicatePoint { ring: usize, index: usize },
+    /// The ring encloses no area, e.g. because its vertices are aligned.
+    ZeroAreaRing { ring: usize },
+    /// Two edges of the ring cross at `(x, y)`.
+    SelfIntersection { ring: usize, x: f64, y: f64 },
+}
+
+impl Display for GeometryWarning {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            GeometryWarning::DuplicatePoint { ring, index } => {
+                write!(fmt, "vertex {} of ring {} is a duplicate", index, ring)
+            }
+            GeometryWarning::ZeroAreaRing { ring } => write!(fmt, "ring {} has no area", ring),
+            GeometryWarning::SelfIntersection { ring, x, y } => {
+                write!(fmt, "ring {} intersects itself at ({}, {})", ring, x, y)
+            }
+        }
+    }
+}
+
+fn coords_f64<T: CoordNum>(line_string: &LineString<T>) -> Vec<(f64, f64)> {
+    line_string
+        .coords()
+        .map(|coord| {
+            (
+                NumCast::from(coord.x).unwrap_or(f64::NAN),
+                NumCast::from(coord.y).unwrap_or(f64::NAN),
+            )
+        })
+        .collect()
+}
+
+fn duplicate_points(ring: usize, coords: &[(f64, f64)], warnings: &mut Vec<GeometryWarning>) {
+    for (index, pair) in coords.windows(2).enumerate() {
+        if pair[0] == pair[1] {
+            warnings.push(GeometryWarning::DuplicatePoint {
+                ring,
+                index: index + 1,
+            });
+        }
+    }
+}
+
+fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+/// Point where the segments cross, if they do so strictly inside both of them.
+fn crossing(a: ((f64, f64), (f64, f64)), b: ((f64, f64), (f64, f64))) -> Option<(f64, f64)> {
+    let (d1, d2) = (cross(a.0, a.1, b.0), cross(a.0, a.1, b.1));
+    let (d3, d4) = (cross(b.0, b.1, a.0), cross(b.0, b.1, a.1));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        let t = d3 / (d3 - d4);
+        Some((
+            a.0 .0 + t * (a.1 .0 - a.0 .0),
+            a.0 .1 + t * (a.1 .1 - a.0 .1),
+        ))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn line_string_warnings<T: CoordNum>(
+    line_string: &LineString<T>,
+) -> Vec<GeometryWarning> {
+    let mut warnings = Vec::new();
+    duplicate_points(0, &coords_f64(line_string), &mut warnings);
+    warnings
+}
+
+pub(crate) fn polygon_warnings<T: CoordNum>(polygon: &Polygon<T>) -> Vec<GeometryWarning> {
+    let mut warnings = Vec::new();
+    let rings = core::iter::once(polygon.exterior()).chain(polygon.interiors());
+    for (ring, line_string) in rings.enumerate() {
+        let coords = coords_f64(line_string);
+        duplicate_points(ring, &coords, &mut warnings);
+        let area: f64 = coords
+            .windows(2)
+            .map(|pair| pair[0].0 * pair[1].1 - pair[1].0 * pair[0].1)
+            .sum();
+        if area == 0.0 {
+            warnings.push(GeometryWarning::ZeroAreaRing { ring });
+        }
+        // the rings are closed, the first and the last edges are adjacent
+        let edges: Vec<_> = coords.windows(2).map(|pair| (pair[0], pair[1])).collect();
+        for (i, a) in edges.iter().enumerate() {
+            for (j, b) in edges.iter().enumerate().skip(i + 2) {
+                if i == 0 && j == edges.len() - 1 {
+                    continue;
+                }
+                if let Some((x, y)) = crossing(*a, *b) {
+                    warnings.push(GeometryWarning::SelfIntersection { ring, x, y });
+                }
+            }
+        }
+    }
+    warnings
+}
+
+impl Svg<'_> {
+    /// Renders the document like its [`Display`] implementation, together with the problems
+    /// found in its geometries: duplicate consecutive points, polygon rings without area and
+    /// self-intersecting rings.
+    pub fn to_string_with_warnings(&self) -> (String, Vec<GeometryWarning>) {
+        (self.to_string(), self.warnings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeometryWarning;
+    use crate::ToSvg;
+    use geo_types::{LineString, Polygon};
+
+    #[test]
+    fn test_to_string_with_warnings() {
+        let bow_tie = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 3.0)]),
+            vec![LineString::from(vec![(0.5, 0.5), (1.0, 1.0), (1.5, 1.5)])],
+        );
+        let line_string = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 0.0)]);
+        let svg = bow_tie.to_svg().and(line_string.to_svg());
+        let (document, warnings) = svg.to_string_with_warnings();
+        assert_eq!(document, svg.to_string());
+        assert_eq!(
+            warnings,
+            vec![
+                GeometryWarning::SelfIntersection {
+                    ring: 0,
+                    x: 1.2,
+                    y: 1.2
+                },
+                GeometryWarning::ZeroAreaRing { ring: 1 },
+                GeometryWarning::DuplicatePoint { ring: 0, index: 2 },
+            ]
+        );
+
+        let square = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]),
+            vec![],
+        );
+        assert_eq!(square.to_svg().to_string_with_warnings().1, vec![]);
+    }
+}
@@ -1,13 +1,21 @@
+use crate::{Style, ToSvgStr};
+use geo_types::{coord, CoordNum, Rect};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::NumCast;
+
+/// Extent of shapes. The default viewbox, with no bounds, is empty: empty geometries have it
+/// and adding it to another viewbox leaves that one unchanged.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct ViewBox {
-    pub min_x: Option<f32>,
-    pub min_y: Option<f32>,
-    pub max_x: Option<f32>,
-    pub max_y: Option<f32>,
+    pub min_x: Option<f64>,
+    pub min_y: Option<f64>,
+    pub max_x: Option<f64>,
+    pub max_y: Option<f64>,
 }
 
 impl ViewBox {
-    pub fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
         Self {
             min_x: Some(min_x),
             min_y: Some(min_y),
@@ -16,62 +24,272 @@ impl ViewBox {
         }
     }
 
+    /// Extent of the geometry drawn with `style`, including its stroke and point radius, as
+    /// the document would show it. Computing it doesn't render anything, so the layout can be
+    /// decided first.
+    pub fn of<G: ToSvgStr + ?Sized>(geometry: &G, style: &Style) -> Self {
+        geometry.viewbox(style)
+    }
+
+    /// Whether a bound is missing, i.e. the viewbox contains nothing.
+    pub fn is_empty(&self) -> bool {
+        self.min_x.is_none() || self.min_y.is_none() || self.max_x.is_none() || self.max_y.is_none()
+    }
+
+    /// Smallest viewbox containing both viewboxes, see [`ViewBox::union`].
     pub fn add(&self, other: &Self) -> Self {
-        Self {
-            min_x: Self::min_option(self.min_x, other.min_x),
-            min_y: Self::min_option(self.min_y, other.min_y),
-            max_x: Self::max_option(self.max_x, other.max_x),
-            max_y: Self::max_option(self.max_y, other.max_y),
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        Self::new(
+            self.min_x().min(other.min_x()),
+            self.min_y().min(other.min_y()),
+            self.max_x().max(other.max_x()),
+            self.max_y().max(other.max_y()),
+        )
+    }
+
+    /// Smallest viewbox containing both viewboxes.
+    pub fn union(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    /// Area shared by both viewboxes, which is empty when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Self::default();
+        }
+        let intersection = Self::new(
+            self.min_x().max(other.min_x()),
+            self.min_y().max(other.min_y()),
+            self.max_x().min(other.max_x()),
+            self.max_y().min(other.max_y()),
+        );
+        if intersection.min_x() > intersection.max_x()
+            || intersection.min_y() > intersection.max_y()
+        {
+            return Self::default();
+        }
+        intersection
+    }
+
+    /// Whether the point lies inside the viewbox or on its edges.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        !self.is_empty()
+            && (self.min_x()..=self.max_x()).contains(&x)
+            && (self.min_y()..=self.max_y()).contains(&y)
+    }
+
+    /// Grows the viewbox by `dx` on the left and right and by `dy` at the top and bottom, or
+    /// shrinks it for negative values.
+    pub fn expand_by(mut self, dx: f64, dy: f64) -> Self {
+        self.min_x = self.min_x.map(|x| x - dx);
+        self.min_y = self.min_y.map(|y| y - dy);
+        self.max_x = self.max_x.map(|x| x + dx);
+        self.max_y = self.max_y.map(|y| y + dy);
+        self
+    }
+
+    /// Width divided by height, or `None` for an empty or flat viewbox.
+    pub fn aspect_ratio(&self) -> Option<f64> {
+        if self.is_empty() || self.height() == 0.0 {
+            return None;
+        }
+        Some(self.width() / self.height())
+    }
+
+    /// Expands the viewbox around its center to the aspect ratio of a `width` by `height`
+    /// canvas, leaving at least `padding` canvas units between the shapes and the edges. A
+    /// padding leaving no room for the shapes is ignored, as is an empty canvas.
+    pub fn fit_to_canvas(self, width: f64, height: f64, padding: f64) -> Self {
+        let no_canvas = !(width > 0.0 && height > 0.0);
+        if self.is_empty() || (self.width() == 0.0 && self.height() == 0.0) || no_canvas {
+            return self;
         }
+        let padding = if 2.0 * padding < width.min(height) {
+            padding.max(0.0)
+        } else {
+            0.0
+        };
+        // canvas units per viewbox unit
+        let scale =
+            ((width - 2.0 * padding) / self.width()).min((height - 2.0 * padding) / self.height());
+        let center_x = self.min_x() + self.width() / 2.0;
+        let center_y = self.min_y() + self.height() / 2.0;
+        let (half_width, half_height) = (width / scale / 2.0, height / scale / 2.0);
+        Self::new(
+            center_x - half_width,
+            center_y - half_height,
+            center_x + half_width,
+            center_y + half_height,
+        )
     }
 
-    pub fn min_x(&self) -> f32 {
+    /// The viewbox as a rectangle, or `None` when it's empty.
+    pub fn to_rect(&self) -> Option<Rect<f64>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(Rect::new(
+            coord! { x: self.min_x(), y: self.min_y() },
+            coord! { x: self.max_x(), y: self.max_y() },
+        ))
+    }
+
+    pub fn min_x(&self) -> f64 {
         self.min_x.unwrap_or_default()
     }
 
-    pub fn min_y(&self) -> f32 {
+    pub fn min_y(&self) -> f64 {
         self.min_y.unwrap_or_default()
     }
 
-    pub fn max_x(&self) -> f32 {
+    pub fn max_x(&self) -> f64 {
         self.max_x.unwrap_or_default()
     }
 
-    pub fn max_y(&self) -> f32 {
+    pub fn max_y(&self) -> f64 {
         self.max_y.unwrap_or_default()
     }
 
-    pub fn width(&self) -> f32 {
+    pub fn width(&self) -> f64 {
         (self.min_x() - self.max_x()).abs()
     }
 
-    pub fn height(&self) -> f32 {
+    pub fn height(&self) -> f64 {
         (self.min_y() - self.max_y()).abs()
     }
 
-    fn min_option(a: Option<f32>, b: Option<f32>) -> Option<f32> {
-        match (a, b) {
-            (Some(a), Some(b)) => Some(a.min(b)),
-            (Some(a), None) => Some(a),
-            (None, Some(b)) => Some(b),
-            (None, None) => None,
-        }
+    pub fn with_margin(self, margin: f64) -> Self {
+        self.expand_by(margin, margin)
     }
 
-    fn max_option(a: Option<f32>, b: Option<f32>) -> Option<f32> {
-        match (a, b) {
-            (Some(a), Some(b)) => Some(a.max(b)),
-            (Some(a), None) => Some(a),
-            (None, Some(b)) => Some(b),
-            (None, None) => None,
+    /// Expands the viewbox outward to the nearest multiples of `step`, so that its bounds stay
+    /// the same when the shapes move slightly. Steps that aren't positive and finite leave the
+    /// viewbox as it is.
+    pub fn rounded(mut self, step: f64) -> Self {
+        if !(step > 0.0 && step.is_finite()) {
+            return self;
         }
+        // dividing by the inverse of steps such as 0.1 gives 0.3 rather than 0.30000000000000004
+        let inverse = (1.0 / step).round();
+        let multiple = |n: f64| {
+            if step < 1.0 && (inverse * step - 1.0).abs() < 1e-9 {
+                n / inverse
+            } else {
+                n * step
+            }
+        };
+        self.min_x = self.min_x.map(|x| multiple((x / step).floor()));
+        self.min_y = self.min_y.map(|y| multiple((y / step).floor()));
+        self.max_x = self.max_x.map(|x| multiple((x / step).ceil()));
+        self.max_y = self.max_y.map(|y| multiple((y / step).ceil()));
+        self
+    }
+}
+
+impl<T: CoordNum> From<Rect<T>> for ViewBox {
+    fn from(rect: Rect<T>) -> Self {
+        let f = |value: T| NumCast::from(value).unwrap_or(f64::NAN);
+        Self::new(
+            f(rect.min().x),
+            f(rect.min().y),
+            f(rect.max().x),
+            f(rect.max().y),
+        )
     }
+}
 
-    pub fn with_margin(mut self, margin: f32) -> Self {
-        self.min_x = self.min_x.map(|x| x - margin);
-        self.min_y = self.min_y.map(|y| y - margin);
-        self.max_x = self.max_x.map(|x| x + margin);
-        self.max_y = self.max_y.map(|y| y + margin);
-        self
+#[cfg(test)]
+mod tests {
+    use super::ViewBox;
+    use crate::{Style, ToSvg};
+    use geo_types::{LineString, Point, Rect};
+
+    #[test]
+    fn test_of() {
+        let point = Point::new(10.0, 20.0);
+        assert_eq!(
+            ViewBox::of(&point, &Style::default()),
+            point.to_svg().viewbox()
+        );
+        let style = Style {
+            radius: 3.0,
+            ..Style::default()
+        };
+        assert_eq!(
+            ViewBox::of(&point, &style),
+            ViewBox::new(6.0, 16.0, 14.0, 24.0)
+        );
+        let line_string = LineString::from(vec![(0.0, 0.0), (4.0, 2.0)]);
+        assert_eq!(
+            ViewBox::of(&line_string.to_svg().and(point.to_svg()), &style),
+            ViewBox::new(-1.0, -1.0, 14.0, 24.0)
+        );
+    }
+
+    #[test]
+    fn test_algebra() {
+        let a = ViewBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = ViewBox::new(5.0, -5.0, 15.0, 5.0);
+        assert_eq!(a.union(&b), ViewBox::new(0.0, -5.0, 15.0, 10.0));
+        assert_eq!(a.intersection(&b), ViewBox::new(5.0, 0.0, 10.0, 5.0));
+        assert!(a
+            .intersection(&ViewBox::new(20.0, 20.0, 30.0, 30.0))
+            .is_empty());
+        assert!(a.intersection(&ViewBox::default()).is_empty());
+
+        assert!(a.contains_point(10.0, 3.0));
+        assert!(!a.contains_point(10.5, 3.0));
+        assert!(!ViewBox::default().contains_point(0.0, 0.0));
+
+        assert_eq!(a.expand_by(2.0, -1.0), ViewBox::new(-2.0, 1.0, 12.0, 9.0));
+        assert_eq!(b.expand_by(0.0, 5.0).aspect_ratio(), Some(0.5));
+        assert_eq!(ViewBox::new(0.0, 1.0, 4.0, 1.0).aspect_ratio(), None);
+    }
+
+    #[test]
+    fn test_fit_to_canvas() {
+        let viewbox = ViewBox::new(0.0, 0.0, 10.0, 5.0);
+        assert_eq!(
+            viewbox.fit_to_canvas(100.0, 100.0, 0.0),
+            ViewBox::new(0.0, -2.5, 10.0, 7.5)
+        );
+        assert_eq!(
+            viewbox.fit_to_canvas(120.0, 120.0, 10.0),
+            ViewBox::new(-1.0, -3.5, 11.0, 8.5)
+        );
+        assert!(ViewBox::default().fit_to_canvas(10.0, 10.0, 0.0).is_empty());
+
+        // too much padding is ignored rather than flipping the viewbox
+        let square = ViewBox::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(square.fit_to_canvas(10.0, 10.0, 6.0), square);
+        assert_eq!(square.fit_to_canvas(10.0, 10.0, 5.0), square);
+        assert_eq!(square.fit_to_canvas(0.0, 10.0, 0.0), square);
+    }
+
+    #[test]
+    fn test_rect_conversion() {
+        let rect = Rect::new((1, 2), (5, 3));
+        let viewbox = ViewBox::from(rect);
+        assert_eq!(viewbox, ViewBox::new(1.0, 2.0, 5.0, 3.0));
+        assert_eq!(viewbox.to_rect(), Some(Rect::new((1.0, 2.0), (5.0, 3.0))));
+        assert_eq!(ViewBox::default().to_rect(), None);
+    }
+
+    #[test]
+    fn test_rounded() {
+        let viewbox = ViewBox::new(-3.2, 11.0, 27.5, 19.9);
+        assert_eq!(viewbox.rounded(10.0), ViewBox::new(-10.0, 10.0, 30.0, 20.0));
+        let viewbox = ViewBox::new(4.123, 52.271, 4.456, 52.3);
+        assert_eq!(viewbox.rounded(0.1), ViewBox::new(4.1, 52.2, 4.5, 52.3));
+        assert_eq!(viewbox.rounded(0.01), ViewBox::new(4.12, 52.27, 4.46, 52.3));
+        assert!(ViewBox::default().rounded(10.0).is_empty());
+        for step in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(viewbox.rounded(step), viewbox);
+        }
     }
 }
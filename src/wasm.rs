@@ -0,0 +1,47 @@
+use crate::{Style, ToSvg};
+use geo_types::GeometryCollection;
+use geojson::GeoJson;
+use std::convert::TryFrom;
+use wasm_bindgen::prelude::*;
+
+fn render_geojson(json: &str, style_json: &str) -> Result<String, String> {
+    let geojson: GeoJson = json
+        .parse()
+        .map_err(|error: geojson::Error| error.to_string())?;
+    let collection =
+        GeometryCollection::<f64>::try_from(&geojson).map_err(|error| error.to_string())?;
+    let style: Style = if style_json.trim().is_empty() {
+        Style::default()
+    } else {
+        serde_json::from_str(style_json).map_err(|error| error.to_string())?
+    };
+    Ok(collection.to_svg().with_style(&style).to_string())
+}
+
+/// Renders a GeoJSON geometry, feature or feature collection into an svg document, ready to
+/// be set as the `innerHTML` of an element. `style_json` is a serialized [`Style`] whose
+/// missing fields take their default value, or an empty string for the default style.
+///
+/// Exported to JavaScript as `renderGeoJsonToSvg(json, styleJson)`, which throws an `Error`
+/// when either document is invalid.
+#[wasm_bindgen(js_name = renderGeoJsonToSvg)]
+pub fn render_geojson_to_svg(json: &str, style_json: &str) -> Result<String, JsError> {
+    render_geojson(json, style_json).map_err(|error| JsError::new(&error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_geojson;
+
+    #[test]
+    fn test_render_geojson() {
+        let point = r#"{"type": "Point", "coordinates": [10.0, 20.0]}"#;
+        assert_eq!(
+            render_geojson(point, r##"{"fill": "#FF0000", "radius": 3.0}"##).unwrap(),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="6 16 8 8"><circle cx="10.0" cy="20.0" r="3" fill="#FF0000"/></svg>"##
+        );
+        assert!(render_geojson(point, "").unwrap().contains("<circle"));
+        assert!(render_geojson("{", "").is_err());
+        assert!(render_geojson(point, r#"{"fill": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use geo_types::{CoordNum, LineString, Polygon};
+use num_traits::NumCast;
+
+/// Twice the signed area of the ring, positive when it is counter-clockwise with the y axis
+/// pointing up.
+fn signed_area<T: CoordNum>(ring: &LineString<T>) -> f64 {
+    ring.lines()
+        .map(|line| {
+            let start: (f64, f64) = (
+                NumCast::from(line.start.x).unwrap_or(0.0),
+                NumCast::from(line.start.y).unwrap_or(0.0),
+            );
+            let end: (f64, f64) = (
+                NumCast::from(line.end.x).unwrap_or(0.0),
+                NumCast::from(line.end.y).unwrap_or(0.0),
+            );
+            start.0 * end.1 - end.0 * start.1
+        })
+        .sum()
+}
+
+fn oriented<T: CoordNum>(ring: &LineString<T>, counter_clockwise: bool) -> LineString<T> {
+    let area = signed_area(ring);
+    if area != 0.0 && (area > 0.0) != counter_clockwise {
+        ring.0.iter().rev().copied().collect()
+    } else {
+        ring.clone()
+    }
+}
+
+/// Copy of the polygon with a counter-clockwise exterior and clockwise holes, as the y axis
+/// points up, i.e. the other way round as drawn in svg.
+pub(crate) fn normalize_winding<T: CoordNum>(polygon: &Polygon<T>) -> Polygon<T> {
+    Polygon::new(
+        oriented(polygon.exterior(), true),
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| oriented(interior, false))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_winding;
+    use geo_types::{LineString, Polygon};
+
+    #[test]
+    fn test_normalize_winding() {
+        let clockwise = LineString::from(vec![(0, 0), (0, 4), (4, 4), (4, 0)]);
+        let counter_clockwise = LineString::from(vec![(1, 1), (2, 1), (2, 2), (1, 2)]);
+        let polygon = Polygon::new(clockwise, vec![counter_clockwise]);
+        let normalized = normalize_winding(&polygon);
+        assert_eq!(
+            normalized.exterior(),
+            &LineString::from(vec![(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)])
+        );
+        assert_eq!(
+            normalized.interiors()[0],
+            LineString::from(vec![(1, 1), (1, 2), (2, 2), (2, 1), (1, 1)])
+        );
+        assert_eq!(normalize_winding(&normalized), normalized);
+    }
+}
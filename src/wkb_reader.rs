@@ -0,0 +1,87 @@
+use crate::Projection;
+use geo_types::Geometry;
+use geozero::error::Result;
+use geozero::wkb::{Ewkb, Wkb};
+use geozero::ToGeo;
+use std::convert::TryInto;
+
+/// Flag of the geometry type of EWKB telling that an SRID follows it.
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Geometry read from EWKB with the SRID it's given in, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EwkbGeometry {
+    pub geometry: Geometry<f64>,
+    pub srid: Option<i32>,
+}
+
+impl EwkbGeometry {
+    /// Projection rendering the geometry from its SRID, see [`Projection::from_srid`], to set
+    /// on its style.
+    pub fn projection(&self) -> Option<Projection> {
+        self.srid.and_then(Projection::from_srid)
+    }
+}
+
+/// Reads an OGC WKB geometry, as returned by `ST_AsBinary`. Elevations and measures are
+/// dropped.
+pub fn from_wkb(bytes: &[u8]) -> Result<Geometry<f64>> {
+    Wkb(bytes).to_geo()
+}
+
+/// Reads a PostGIS EWKB geometry, as geometry columns are returned, with its SRID.
+/// Elevations and measures are dropped.
+pub fn from_ewkb(bytes: &[u8]) -> Result<EwkbGeometry> {
+    Ok(EwkbGeometry {
+        geometry: Ewkb(bytes).to_geo()?,
+        srid: ewkb_srid(bytes),
+    })
+}
+
+/// SRID of the header of an EWKB geometry, 0 being unknown.
+fn ewkb_srid(bytes: &[u8]) -> Option<i32> {
+    let (&byte_order, header) = bytes.split_first()?;
+    let read_u32 = |offset: usize| {
+        let word = header.get(offset..offset + 4)?.try_into().ok()?;
+        Some(match byte_order {
+            0 => u32::from_be_bytes(word),
+            _ => u32::from_le_bytes(word),
+        })
+    };
+    if read_u32(0)? & EWKB_SRID_FLAG == 0 {
+        return None;
+    }
+    Some(read_u32(4)? as i32).filter(|&srid| srid != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_ewkb, from_wkb};
+    use crate::Projection;
+    use geo_types::{Geometry, Point};
+
+    fn point_bytes(header: &[u8]) -> Vec<u8> {
+        let coords = [1.0f64.to_le_bytes(), 2.0f64.to_le_bytes()].concat();
+        [header, &coords].concat()
+    }
+
+    #[test]
+    fn test_from_wkb() {
+        let point = Geometry::Point(Point::new(1.0, 2.0));
+        assert_eq!(from_wkb(&point_bytes(&[1, 1, 0, 0, 0])).unwrap(), point);
+        assert!(from_wkb(&[1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_from_ewkb() {
+        let point = Geometry::Point(Point::new(1.0, 2.0));
+        let ewkb = from_ewkb(&point_bytes(&[1, 1, 0, 0, 0x20, 0xe6, 0x10, 0, 0])).unwrap();
+        assert_eq!(ewkb.geometry, point);
+        assert_eq!(ewkb.srid, Some(4326));
+        assert_eq!(ewkb.projection(), Some(Projection::WebMercator));
+
+        let ewkb = from_ewkb(&point_bytes(&[1, 1, 0, 0, 0])).unwrap();
+        assert_eq!(ewkb.srid, None);
+        assert_eq!(ewkb.projection(), None);
+    }
+}